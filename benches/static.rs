@@ -0,0 +1,43 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use mats::{Mat, Mat4, Vec4};
+
+fn mat4_mul(c: &mut Criterion) {
+    let a = Mat4::<f32>::new([
+        [0.5, 1.25, -2.0, 3.0],
+        [4.0, -0.75, 6.5, 7.0],
+        [-8.0, 9.5, 10.0, -11.25],
+        [12.0, 13.0, -14.5, 15.0],
+    ]);
+    let b = a.T();
+    c.bench_function("Mat4 * Mat4", |bench| {
+        bench.iter(|| black_box(a) * black_box(b))
+    });
+}
+
+fn mat4_mul_vec4(c: &mut Criterion) {
+    let m = Mat4::<f32>::new([
+        [0.5, 1.25, -2.0, 3.0],
+        [4.0, -0.75, 6.5, 7.0],
+        [-8.0, 9.5, 10.0, -11.25],
+        [12.0, 13.0, -14.5, 15.0],
+    ]);
+    let v = Vec4::new([[1.0, 2.0, 3.0, 1.0]]);
+    c.bench_function("Mat4 * Vec4", |bench| {
+        bench.iter(|| black_box(m) * black_box(v))
+    });
+}
+
+fn transpose(c: &mut Criterion) {
+    let m4 = Mat4::<f32>::init(1.5);
+    c.bench_function("Mat4 transpose", |bench| {
+        bench.iter(|| black_box(m4).transpose())
+    });
+
+    let m32 = Mat::<32, 32, f64>::init(1.5);
+    c.bench_function("Mat<32, 32> transpose", |bench| {
+        bench.iter(|| black_box(m32).transpose())
+    });
+}
+
+criterion_group!(benches, mat4_mul, mat4_mul_vec4, transpose);
+criterion_main!(benches);
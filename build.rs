@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::env;
 use std::fs::File;
-use std::io::{Read, Write};
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 fn gen_order(
@@ -24,14 +24,14 @@ fn gen_order(
 fn get_order(index: &Vec<&'static str>) -> Vec<Vec<&'static str>> {
     let mut result = vec![];
     for i in 0..index.len() {
-        gen_order(&mut result, &index, vec![], i as i32);
+        gen_order(&mut result, index, vec![], i as i32);
     }
     result
 }
 
 fn is_valid(ls: &[&str], has: &[&str]) -> bool {
     for s in ls {
-        if !has.contains(&s) {
+        if !has.contains(s) {
             return false;
         }
     }
@@ -48,11 +48,45 @@ fn gen_code(path: &PathBuf) {
     // writeln!(file, "//! Generated by `build.rs`").unwrap();
 
     for i in 2..5 {
-        writeln!(file, "impl<T:Copy> Vec{}<T> {{", i).unwrap();
         let has = &index[..i];
+        // List what is generated on the impl block, so it shows up in the docs.
+        let swizzles = result
+            .iter()
+            .filter(|ls| is_valid(ls, has))
+            .map(|ls| format!("`{}`", ls.join("")))
+            .collect::<Vec<_>>();
+        writeln!(
+            file,
+            "/// Generated by `build.rs`: GLSL-style swizzles and component setters of `Vec{}`.",
+            i
+        )
+        .unwrap();
+        writeln!(file, "///").unwrap();
+        writeln!(
+            file,
+            "/// Every sequence of 1 to 4 of the components {} is a method returning",
+            has.iter()
+                .map(|c| format!("`{}`", c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+        .unwrap();
+        writeln!(
+            file,
+            "/// `T` or `Vec2`..`Vec4`, and every component has `set_*` and `with_*` methods."
+        )
+        .unwrap();
+        writeln!(file, "///").unwrap();
+        writeln!(file, "/// Swizzles ({}):", swizzles.len()).unwrap();
+        let lines = swizzles
+            .chunks(12)
+            .map(|line| line.join(", "))
+            .collect::<Vec<_>>();
+        writeln!(file, "/// {}.", lines.join(",\n/// ")).unwrap();
+        writeln!(file, "impl<T:Copy> Vec{}<T> {{", i).unwrap();
         for ls in &result {
             let res_type = if ls.len() == 1 {
-                format!("T")
+                "T".to_string()
             } else {
                 format!("Vec{}<T>", ls.len())
             };
@@ -64,7 +98,6 @@ fn gen_code(path: &PathBuf) {
                 writeln!(file, "    /// use mats::*;").unwrap();
                 writeln!(file, "    ///").unwrap();
                 let args = (0..i)
-                    .into_iter()
                     .map(|i| format!("{}", i))
                     .collect::<Vec<_>>()
                     .join(", ");
@@ -95,18 +128,83 @@ fn gen_code(path: &PathBuf) {
                     if ls.len() > 1 {
                         writeln!(file, ",").unwrap();
                     } else {
-                        writeln!(file, "").unwrap();
+                        writeln!(file).unwrap();
                     }
                 }
                 if ls.len() > 1 {
                     writeln!(file, "        ]])").unwrap();
                 }
                 writeln!(file, "    }}").unwrap();
-                writeln!(file, "").unwrap();
+                writeln!(file).unwrap();
             }
         }
+        for (k, c) in has.iter().enumerate() {
+            let args = (0..i)
+                .map(|i| format!("{}", i))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let changed = (0..i)
+                .map(|j| {
+                    if j == k {
+                        "9".to_string()
+                    } else {
+                        format!("{}", j)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            writeln!(file, "    /// Set the `{}` component.", c).unwrap();
+            writeln!(file, "    ///").unwrap();
+            writeln!(file, "    /// # Example").unwrap();
+            writeln!(file, "    /// ```").unwrap();
+            writeln!(file, "    /// use mats::*;").unwrap();
+            writeln!(file, "    ///").unwrap();
+            writeln!(file, "    /// let mut v = Vec{}::new([[{}]]);", i, args).unwrap();
+            writeln!(file, "    /// v.set_{}(9);", c).unwrap();
+            writeln!(file, "    ///").unwrap();
+            writeln!(
+                file,
+                "    /// assert_eq!(v, Vec{}::new([[{}]]));",
+                i, changed
+            )
+            .unwrap();
+            writeln!(file, "    /// ```").unwrap();
+            writeln!(file, "    #[inline]").unwrap();
+            writeln!(file, "    pub fn set_{}(&mut self, value: T) {{", c).unwrap();
+            writeln!(file, "        self[{}] = value;", k).unwrap();
+            writeln!(file, "    }}").unwrap();
+            writeln!(file).unwrap();
+
+            writeln!(
+                file,
+                "    /// Return the vector with the `{}` component replaced.",
+                c
+            )
+            .unwrap();
+            writeln!(file, "    ///").unwrap();
+            writeln!(file, "    /// # Example").unwrap();
+            writeln!(file, "    /// ```").unwrap();
+            writeln!(file, "    /// use mats::*;").unwrap();
+            writeln!(file, "    ///").unwrap();
+            writeln!(file, "    /// let v = Vec{}::new([[{}]]);", i, args).unwrap();
+            writeln!(file, "    ///").unwrap();
+            writeln!(
+                file,
+                "    /// assert_eq!(v.with_{}(9), Vec{}::new([[{}]]));",
+                c, i, changed
+            )
+            .unwrap();
+            writeln!(file, "    /// ```").unwrap();
+            writeln!(file, "    #[inline]").unwrap();
+            writeln!(file, "    pub fn with_{}(mut self, value: T) -> Self {{", c).unwrap();
+            writeln!(file, "        self[{}] = value;", k).unwrap();
+            writeln!(file, "        self").unwrap();
+            writeln!(file, "    }}").unwrap();
+            writeln!(file).unwrap();
+        }
         writeln!(file, "}}").unwrap();
-        writeln!(file, "").unwrap();
+        writeln!(file).unwrap();
     }
 }
 
@@ -8,7 +8,7 @@ use glium::{
     winit::{event_loop::EventLoopBuilder, window::Window},
 };
 use image::GenericImageView;
-use mats::graphics::*;
+use mats::{Deg, graphics::*};
 
 use crate::opengl::{Drawable, MyWindow};
 
@@ -103,7 +103,7 @@ impl Drawable for Canvas {
         );
 
         let pre = perspective(
-            45.0,
+            Deg(45.0),
             window.inner_size().width as f32 / window.inner_size().height as f32,
             0.1,
             100.0,
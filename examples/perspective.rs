@@ -10,7 +10,7 @@ use glium::{
     winit::{event_loop::EventLoopBuilder, window::Window},
 };
 use image::GenericImageView;
-use mats::{graphics::*, radian};
+use mats::{Deg, graphics::*, radian};
 
 use crate::opengl::{Drawable, MyWindow};
 
@@ -108,7 +108,7 @@ impl Drawable for Canvas {
         let view = translate3d([0.0, 0.0, -5.0].into());
         // let projection = perspective(45.0, 1.0, 0.1, 100.0);
         let pre = perspective(
-            45.0,
+            Deg(45.0),
             window.inner_size().width as f32 / window.inner_size().height as f32,
             0.1,
             100.0,
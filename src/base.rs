@@ -1,6 +1,6 @@
 use crate::{Mat, UnitOne, Zero};
 
-impl<T: Default, const SIZE: usize> Mat<SIZE, SIZE, T>
+impl<T, const SIZE: usize> Mat<SIZE, SIZE, T>
 where
     T: UnitOne + Zero,
 {
@@ -36,4 +36,478 @@ where
     pub fn I() -> Self {
         Self::unit_one()
     }
+
+    /// Returns the permutation matrix `P` such that the `k`-th row of `P * m` is the
+    /// `perm[k]`-th row of `m`, see `Mat::permute_rows`.
+    ///
+    /// # Panics
+    /// Panics if `perm` is not a permutation of `0..SIZE`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let p = Mat::<3, 3, i32>::permutation([2, 0, 1]);
+    ///
+    /// assert_eq!(p.to_rows_array_2d(), [[0, 0, 1], [1, 0, 0], [0, 1, 0]]);
+    /// ```
+    #[inline]
+    pub fn permutation(perm: [usize; SIZE]) -> Self {
+        let mut seen = [false; SIZE];
+        let mut mat = Self::zero();
+        for (k, &p) in perm.iter().enumerate() {
+            assert!(p < SIZE && !seen[p], "{perm:?} is not a permutation");
+            seen[p] = true;
+            mat.data[p][k] = T::unit_one();
+        }
+        mat
+    }
+}
+
+impl<T, const SIZE: usize> Mat<SIZE, SIZE, T>
+where
+    T: UnitOne + Zero + core::ops::Add<Output = T> + core::ops::Neg<Output = T>,
+{
+    /// Returns the 1D finite-difference Laplacian `tridiag(-1, 2, -1)` on `SIZE`
+    /// points with Dirichlet boundaries (the negative of the second difference).
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let l = Mat::<3, 3, i32>::laplacian_1d();
+    ///
+    /// assert_eq!(l.to_rows_array_2d(), [[2, -1, 0], [-1, 2, -1], [0, -1, 2]]);
+    /// ```
+    #[inline]
+    pub fn laplacian_1d() -> Self {
+        let two = T::unit_one() + T::unit_one();
+        let mut mat = Self::zero();
+        for i in 0..SIZE {
+            mat.data[i][i] = two;
+            if i > 0 {
+                mat.data[i - 1][i] = -T::unit_one();
+                mat.data[i][i - 1] = -T::unit_one();
+            }
+        }
+        mat
+    }
+
+    /// Returns the 5-point finite-difference Laplacian on an `nx` by `SIZE / nx`
+    /// grid with Dirichlet boundaries, with the points numbered along x first.
+    ///
+    /// # Panics
+    /// Panics if `nx` is zero or does not divide `SIZE`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let l = Mat::<4, 4, i32>::laplacian_2d(2);
+    ///
+    /// assert_eq!(
+    ///     l.to_rows_array_2d(),
+    ///     [[4, -1, -1, 0], [-1, 4, 0, -1], [-1, 0, 4, -1], [0, -1, -1, 4]]
+    /// );
+    /// ```
+    #[inline]
+    pub fn laplacian_2d(nx: usize) -> Self {
+        assert!(
+            nx > 0 && SIZE.is_multiple_of(nx),
+            "grid width {nx} does not divide {SIZE}"
+        );
+        let one = T::unit_one();
+        let four = one + one + one + one;
+        let mut mat = Self::zero();
+        for k in 0..SIZE {
+            mat.data[k][k] = four;
+            if k % nx > 0 {
+                mat.data[k - 1][k] = -one;
+                mat.data[k][k - 1] = -one;
+            }
+            if k >= nx {
+                mat.data[k - nx][k] = -one;
+                mat.data[k][k - nx] = -one;
+            }
+        }
+        mat
+    }
+}
+
+impl<T: crate::Float, const SIZE: usize> Mat<SIZE, SIZE, T> {
+    /// Returns the Hilbert matrix with elements `1 / (i + j + 1)`, a classic
+    /// ill-conditioned test matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat3;
+    ///
+    /// let h = Mat3::<f64>::hilbert();
+    ///
+    /// assert_eq!(h[(0, 0)], 1.0);
+    /// assert_eq!(h[(1, 2)], 0.25);
+    /// assert_eq!(h[(2, 2)], 0.2);
+    /// ```
+    #[inline]
+    pub fn hilbert() -> Self {
+        let mut mat = Self::zero();
+        let mut denominator = T::zero();
+        // The anti-diagonal `i + j = s` shares the denominator `s + 1`.
+        for s in 0..SIZE.saturating_mul(2).saturating_sub(1) {
+            denominator += T::unit_one();
+            for j in s.saturating_sub(SIZE - 1)..=s.min(SIZE - 1) {
+                mat.data[j][s - j] = T::unit_one() / denominator;
+            }
+        }
+        mat
+    }
+}
+
+impl<T: Copy, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    /// Returns the Toeplitz matrix, constant along each diagonal, with the given
+    /// first row and first column.
+    ///
+    /// The corner element is taken from `first_col`, so `first_row[0]` is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let t = Mat::<3, 4, i32>::toeplitz(&[0, 1, 2, 3], &[9, 4, 5]);
+    ///
+    /// assert_eq!(t.to_rows_array_2d(), [[9, 1, 2, 3], [4, 9, 1, 2], [5, 4, 9, 1]]);
+    /// ```
+    #[inline]
+    pub fn toeplitz(first_row: &[T; COLS], first_col: &[T; ROWS]) -> Self {
+        Self {
+            data: core::array::from_fn(|j| {
+                core::array::from_fn(|i| {
+                    if i >= j {
+                        first_col[i - j]
+                    } else {
+                        first_row[j - i]
+                    }
+                })
+            }),
+        }
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Copy + UnitOne + core::ops::Mul<Output = T>,
+{
+    /// Returns the Vandermonde matrix of `points` with the powers `0..COLS` in
+    /// increasing order, so that `v * coefficients` evaluates the polynomial of
+    /// degree `COLS - 1` at every point.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat, Vec3};
+    ///
+    /// let v = Mat::<3, 3, i32>::vandermonde(&[1, 2, 3]);
+    /// // 1 + 2x + 3x²
+    /// let coefficients = Vec3::new([[1, 2, 3]]);
+    ///
+    /// assert_eq!(v * coefficients, Vec3::new([[6, 17, 34]]));
+    /// ```
+    #[inline]
+    pub fn vandermonde(points: &[T; ROWS]) -> Self {
+        let mut powers = [T::unit_one(); ROWS];
+        Self {
+            // `from_fn` builds the columns in order, each one power higher.
+            data: core::array::from_fn(|_| {
+                let col = powers;
+                for (p, &x) in powers.iter_mut().zip(points) {
+                    *p = *p * x;
+                }
+                col
+            }),
+        }
+    }
+}
+
+/// Constants and `const fn` constructors for the float element types, usable in
+/// `const` items where the generic `UnitOne`/`Zero` path is not.
+macro_rules! impl_const_constructors {
+    ($($t:ty),*) => {
+        $(
+            impl<const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, $t> {
+                /// The matrix with all elements zero.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Mat2x3;
+                ///
+                /// const Z: Mat2x3<f32> = Mat2x3::<f32>::ZERO;
+                ///
+                /// assert_eq!(Z.raw_data(), &[[0.0; 2]; 3]);
+                /// ```
+                pub const ZERO: Self = Self {
+                    data: [[0.0; ROWS]; COLS],
+                };
+            }
+
+            impl<const SIZE: usize> Mat<SIZE, SIZE, $t> {
+                /// The identity matrix.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Mat4;
+                ///
+                /// const I: Mat4<f64> = Mat4::<f64>::IDENTITY;
+                ///
+                /// assert_eq!(I, Mat4::<f64>::I());
+                /// ```
+                pub const IDENTITY: Self = {
+                    let mut data = [[0.0; SIZE]; SIZE];
+                    let mut i = 0;
+                    while i < SIZE {
+                        data[i][i] = 1.0;
+                        i += 1;
+                    }
+                    Self { data }
+                };
+            }
+
+            impl Mat<4, 4, $t> {
+                /// Create a 3D translation matrix in a `const` context.
+                ///
+                /// This is the `const` counterpart of `graphics::translate3d`.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::{Mat4, Vec4};
+                ///
+                /// const T: Mat4<f32> = Mat4::<f32>::translation(1.0, 2.0, 3.0);
+                ///
+                /// assert_eq!(T * Vec4::new([[1.0, 1.0, 1.0, 1.0]]), Vec4::new([[2.0, 3.0, 4.0, 1.0]]));
+                /// ```
+                #[inline]
+                pub const fn translation(x: $t, y: $t, z: $t) -> Self {
+                    let mut m = Self::IDENTITY;
+                    m.data[3] = [x, y, z, 1.0];
+                    m
+                }
+
+                /// Create a 3D scaling matrix in a `const` context.
+                ///
+                /// This is the `const` counterpart of `graphics::scale3d`.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::{Mat4, Vec4};
+                ///
+                /// const S: Mat4<f32> = Mat4::<f32>::scaling(2.0, 3.0, 4.0);
+                ///
+                /// assert_eq!(S * Vec4::new([[1.0, 1.0, 1.0, 1.0]]), Vec4::new([[2.0, 3.0, 4.0, 1.0]]));
+                /// ```
+                #[inline]
+                pub const fn scaling(x: $t, y: $t, z: $t) -> Self {
+                    let mut m = Self::IDENTITY;
+                    m.data[0][0] = x;
+                    m.data[1][1] = y;
+                    m.data[2][2] = z;
+                    m
+                }
+            }
+        )*
+    };
+}
+
+impl_const_constructors!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mat, Mat4, Zero};
+
+    const TRANSFORMS: [Mat4<f32>; 4] = [
+        Mat4::<f32>::IDENTITY,
+        Mat4::<f32>::translation(16.0, 0.0, 0.0),
+        Mat4::<f32>::translation(0.0, 16.0, -1.0),
+        Mat4::<f32>::scaling(0.5, 0.5, 1.0),
+    ];
+
+    #[test]
+    fn test_const_constructors() {
+        assert_eq!(TRANSFORMS[0], Mat4::I());
+        assert_eq!(Mat4::<f32>::ZERO, Mat4::zero());
+
+        let translate = |x, y, z| {
+            Mat4::I() + {
+                let mut m = Mat4::zero();
+                m[(0, 3)] = x;
+                m[(1, 3)] = y;
+                m[(2, 3)] = z;
+                m
+            }
+        };
+        assert_eq!(TRANSFORMS[1], translate(16.0, 0.0, 0.0));
+        assert_eq!(TRANSFORMS[2], translate(0.0, 16.0, -1.0));
+
+        #[cfg(feature = "graphics")]
+        {
+            use crate::{
+                Vec3,
+                graphics::{scale3d, translate3d},
+            };
+
+            assert_eq!(TRANSFORMS[2], translate3d(Vec3::new([[0.0, 16.0, -1.0]])));
+            assert_eq!(TRANSFORMS[3], scale3d(Vec3::new([[0.5, 0.5, 1.0]])));
+        }
+    }
+
+    #[test]
+    fn test_swaps_and_permutations() {
+        let m = Mat4::<i32>::from_rows_array(&[
+            1, 2, 3, 4, //
+            5, 6, 7, 8, //
+            9, 10, 11, 12, //
+            13, 14, 15, 16,
+        ]);
+
+        let mut s = m;
+        s.swap_rows(1, 3);
+        s.swap_cols(0, 2);
+        assert_ne!(s, m);
+        s.swap_cols(0, 2);
+        s.swap_rows(1, 3);
+        assert_eq!(s, m);
+
+        for perm in [[0, 1, 2, 3], [3, 2, 1, 0], [1, 3, 0, 2]] {
+            assert_eq!(Mat4::<i32>::permutation(perm) * m, m.permute_rows(&perm));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_permutation_rejects_duplicates() {
+        Mat4::<i32>::permutation([0, 1, 1, 3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatting() {
+        let m = Mat::<2, 3, f64>::from_rows_array_2d([[1.0, -2.5, 1.0 / 3.0], [4.0, 5.0, 6.0]]);
+
+        assert_eq!(format!("{m}"), "[[1, -2.5, 0.3333333333333333], [4, 5, 6]]");
+        assert_eq!(
+            format!("{m:.2}"),
+            "[[1.00, -2.50, 0.33], [4.00, 5.00, 6.00]]"
+        );
+        assert_eq!(
+            format!("{m:#6.2}"),
+            "[\n    [  1.00,  -2.50,   0.33],\n    [  4.00,   5.00,   6.00],\n]"
+        );
+        assert_eq!(format!("{:+}", Mat::<1, 1, i32>::new([[3]])), "[[+3]]");
+        assert_eq!(format!("{:#}", Mat::<0, 2, i32>::new([[], []])), "[]");
+
+        // Debug prints the same rows, labelled with the type.
+        assert_eq!(
+            format!("{m:.1?}"),
+            "Mat<2, 3, f64> [[1.0, -2.5, 0.3], [4.0, 5.0, 6.0]]"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]])
+            ),
+            "Mat<2, 3, i32> [[1, 2, 3], [4, 5, 6]]"
+        );
+        assert_eq!(
+            format!("{:#?}", Mat::<1, 2, i32>::new([[1], [2]])),
+            "Mat<1, 2, i32> [\n    [1, 2],\n]"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_index_panic_messages() {
+        fn message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+            let payload = std::panic::catch_unwind(f).unwrap_err();
+            payload
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_default()
+        }
+
+        let m = Mat::<4, 3, i32>::default();
+        assert_eq!(
+            message(|| _ = m[(5, 2)]),
+            "index (5, 2) out of bounds for 4x3 Mat"
+        );
+        assert_eq!(
+            message(|| _ = m[(1, 3)]),
+            "index (1, 3) out of bounds for 4x3 Mat"
+        );
+        assert_eq!(
+            message(|| Mat::<2, 1, i32>::default()[2] = 1),
+            "index (2, 0) out of bounds for 2x1 Mat"
+        );
+        assert_eq!(
+            message(|| _ = crate::RowVec::<3, i32>::from([1, 2, 3])[3]),
+            "index (0, 3) out of bounds for 1x3 Mat"
+        );
+    }
+
+    #[test]
+    fn test_structured_matrices() {
+        use crate::Vec;
+
+        // The eigenvectors of the 1D Laplacian are sampled sines.
+        const N: usize = 5;
+        let l = Mat::<N, N, f64>::laplacian_1d();
+        let h = core::f64::consts::PI / (N + 1) as f64;
+        for k in 1..=N {
+            let v = Vec::<N, f64>::new([core::array::from_fn(|i| {
+                ((i + 1) as f64 * k as f64 * h).sin()
+            })]);
+            let lambda = 2.0 - 2.0 * (k as f64 * h).cos();
+            let error = l * v - v * lambda;
+            assert!(error.inner_product(&error) < 1e-24);
+        }
+
+        let l2 = Mat::<6, 6, i32>::laplacian_2d(3);
+        assert_eq!(l2, l2.T());
+        assert_eq!(l2.sum(), 4 * 6 - 2 * (2 * 2 + 3));
+
+        // A Vandermonde matrix maps coefficients to the sampled polynomial exactly.
+        let xs = [-1.0, 0.5, 2.0, 3.0];
+        let v = Mat::<4, 4, f64>::vandermonde(&xs);
+        let coefficients = Vec::<4, f64>::new([[1.0, -2.0, 0.5, 1.0]]);
+        let ys = v * coefficients;
+        for (i, &x) in xs.iter().enumerate() {
+            assert_eq!(ys[i], 1.0 - 2.0 * x + 0.5 * x * x + x * x * x);
+        }
+
+        let third = 1.0 / 3.0;
+        assert_eq!(
+            Mat::<3, 3, f64>::hilbert().to_rows_array_2d(),
+            [[1.0, 0.5, third], [0.5, third, 0.25], [third, 0.25, 0.2]]
+        );
+        assert_eq!(Mat::<0, 0, f64>::hilbert(), Mat::new([]));
+    }
+
+    #[test]
+    fn test_vandermonde_solve() {
+        use crate::Vec;
+
+        // Sampling a polynomial at distinct points and solving recovers it.
+        let xs = [-2.0, 0.5, 1.0, 3.0];
+        let v = Mat::<4, 4, f64>::vandermonde(&xs);
+        let coefficients = Vec::<4, f64>::new([[4.0, -3.0, 0.25, 2.0]]);
+        let ys = v * coefficients;
+
+        let solved = v.solve_least_squares(&ys).unwrap();
+        let error = solved - coefficients;
+        assert!(error.inner_product(&error) < 1e-24, "{solved:?}");
+
+        let inverted = v.inverse().unwrap() * ys;
+        let error = inverted - coefficients;
+        assert!(error.inner_product(&error) < 1e-24, "{inverted:?}");
+
+        // Repeated points make the system singular.
+        let repeated = Mat::<3, 3, f64>::vandermonde(&[1.0, 2.0, 1.0]);
+        assert_eq!(repeated.solve_least_squares(&Vec::new([[1.0; 3]])), None);
+    }
 }
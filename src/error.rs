@@ -0,0 +1,117 @@
+use crate::FitError;
+#[cfg(feature = "graphics")]
+use crate::graphics::ProjectionError;
+
+/// The error type of the crate, which the error types of the modules convert into.
+///
+/// Every error of this crate is `Send + Sync + 'static`, so it can be boxed or passed
+/// to error-reporting crates as is. More variants may be added in minor releases.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "graphics")]
+/// # {
+/// use mats::{Error, graphics::try_perspective};
+///
+/// fn projection() -> Result<mats::Mat4<f32>, Error> {
+///     Ok(try_perspective(1.0, 0.0, 0.1, 100.0)?)
+/// }
+///
+/// assert_eq!(projection().unwrap_err().to_string(), "invalid projection: aspect ratio must be positive");
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A polynomial fit was given unusable points.
+    Fit(FitError),
+    /// A projection constructor got degenerate parameters.
+    #[cfg(feature = "graphics")]
+    Projection(ProjectionError),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::Fit(ref e) => write!(f, "invalid polynomial fit: {e}"),
+            #[cfg(feature = "graphics")]
+            Self::Projection(ref e) => write!(f, "invalid projection: {e}"),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match *self {
+            Self::Fit(ref e) => Some(e),
+            #[cfg(feature = "graphics")]
+            Self::Projection(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<FitError> for Error {
+    #[inline]
+    fn from(value: FitError) -> Self {
+        Self::Fit(value)
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl From<ProjectionError> for Error {
+    #[inline]
+    fn from(value: ProjectionError) -> Self {
+        Self::Projection(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_is_thread_safe() {
+        fn assert_error<E: core::error::Error + Send + Sync + 'static>() {}
+        assert_error::<Error>();
+        assert_error::<FitError>();
+        #[cfg(feature = "graphics")]
+        assert_error::<ProjectionError>();
+    }
+
+    #[test]
+    #[cfg(all(feature = "graphics", feature = "std"))]
+    fn test_projection_errors() {
+        use core::error::Error as _;
+
+        let all = [
+            (
+                ProjectionError::FieldOfView,
+                "field of view must be in (0, π)",
+            ),
+            (
+                ProjectionError::AspectRatio,
+                "aspect ratio must be positive",
+            ),
+            (ProjectionError::NearPlane, "near plane must be positive"),
+            (
+                ProjectionError::DepthRange,
+                "near and far planes must differ",
+            ),
+            (
+                ProjectionError::Width,
+                "left and right planes must be finite and differ",
+            ),
+            (
+                ProjectionError::Height,
+                "top and bottom planes must be finite and differ",
+            ),
+        ];
+        for (e, message) in all {
+            assert_eq!(e.to_string(), message);
+
+            let error = Error::from(e);
+            assert_eq!(error.to_string(), format!("invalid projection: {message}"));
+            assert_eq!(error.source().unwrap().to_string(), message);
+        }
+    }
+}
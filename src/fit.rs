@@ -0,0 +1,240 @@
+use crate::{Float, Mat, Vec};
+
+/// The error returned by [`fit_polynomial`] and [`fit_polynomial_slice`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitError {
+    /// There are fewer points than coefficients.
+    TooFewPoints,
+    /// The points do not determine the polynomial, e.g. repeated `x` values.
+    Singular,
+    /// The slices of `x` and `y` values have different lengths.
+    LengthMismatch,
+}
+
+impl core::fmt::Display for FitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::TooFewPoints => "need at least as many points as coefficients",
+            Self::Singular => "the points do not determine the polynomial",
+            Self::LengthMismatch => "xs and ys must have the same length",
+        })
+    }
+}
+
+impl core::error::Error for FitError {}
+
+/// Fits the polynomial `c[0] + c[1] x + ... + c[N - 1] x^(N - 1)` of degree `N - 1`
+/// to the `M` points `(xs[i], ys[i])` by least squares.
+///
+/// The design matrix is [`Mat::vandermonde`] of `xs`, and the system is solved with
+/// [`Mat::solve_least_squares`], which does not form the normal equations. The
+/// point count is part of the type, so `xs` and `ys` always have the same length;
+/// use [`fit_polynomial_slice`] when it is only known at run time. The fit does not
+/// allocate and is exact up to rounding when `M == N`.
+///
+/// # Errors
+/// Returns a [`FitError`] if there are fewer than `N` points, or the points do not
+/// determine the polynomial.
+///
+/// # Example
+/// ```
+/// use mats::{evaluate_polynomial, fit_polynomial};
+///
+/// // y = 1 - 2x + x²
+/// let c = fit_polynomial::<3, _, f64>(&[0.0, 1.0, 3.0], &[1.0, 0.0, 4.0]).unwrap();
+///
+/// assert!((evaluate_polynomial(c.as_ref(), 2.0) - 1.0).abs() < 1e-12);
+/// assert!((c[2] - 1.0).abs() < 1e-12);
+/// ```
+pub fn fit_polynomial<const N: usize, const M: usize, T: Float>(
+    xs: &[T; M],
+    ys: &[T; M],
+) -> Result<Vec<N, T>, FitError> {
+    if M < N {
+        return Err(FitError::TooFewPoints);
+    }
+    Mat::<M, N, T>::vandermonde(xs)
+        .solve_least_squares(&Vec::new([*ys]))
+        .ok_or(FitError::Singular)
+}
+
+/// Fits a polynomial like [`fit_polynomial`], to points whose count is only known
+/// at run time.
+///
+/// The points are streamed one at a time into the triangular factor of the
+/// Vandermonde matrix with Givens rotations, so the fit neither allocates nor
+/// forms the normal equations. The resulting square system is then solved with
+/// [`Mat::solve_least_squares`].
+///
+/// # Errors
+/// Returns [`FitError::LengthMismatch`] if `xs` and `ys` have different lengths,
+/// and otherwise the same errors as [`fit_polynomial`].
+///
+/// # Example
+/// ```
+/// use mats::{FitError, fit_polynomial_slice};
+///
+/// let (xs, ys) = (vec![0.0, 1.0, 2.0, 3.0], vec![1.0, 3.0, 5.0, 7.0]);
+/// let c = fit_polynomial_slice::<2, f64>(&xs, &ys).unwrap();
+///
+/// assert!((c[0] - 1.0).abs() < 1e-12 && (c[1] - 2.0).abs() < 1e-12);
+/// assert_eq!(
+///     fit_polynomial_slice::<2, f64>(&xs, &ys[1..]),
+///     Err(FitError::LengthMismatch)
+/// );
+/// ```
+pub fn fit_polynomial_slice<const N: usize, T: Float>(
+    xs: &[T],
+    ys: &[T],
+) -> Result<Vec<N, T>, FitError> {
+    if xs.len() != ys.len() {
+        return Err(FitError::LengthMismatch);
+    }
+    if xs.len() < N {
+        return Err(FitError::TooFewPoints);
+    }
+    // `r` is the upper triangle of the factor, row by row, and `z` the rotated
+    // right-hand side.
+    let mut r = [[T::zero(); N]; N];
+    let mut z = [T::zero(); N];
+    for (&x, &y) in xs.iter().zip(ys) {
+        let mut power = T::unit_one();
+        let mut row = [T::zero(); N];
+        for p in &mut row {
+            *p = power;
+            power = power * x;
+        }
+        let mut y = y;
+        for k in 0..N {
+            if row[k] == T::zero() {
+                continue;
+            }
+            // Rotate row `k` of `r` and the new row so the latter loses entry `k`.
+            let h = (r[k][k] * r[k][k] + row[k] * row[k]).sqrt();
+            let (c, s) = (r[k][k] / h, row[k] / h);
+            for j in k..N {
+                let (a, b) = (r[k][j], row[j]);
+                r[k][j] = c * a + s * b;
+                row[j] = c * b - s * a;
+            }
+            let a = z[k];
+            z[k] = c * a + s * y;
+            y = c * y - s * a;
+        }
+    }
+    Mat::<N, N, T>::from_rows_array_2d(r)
+        .solve_least_squares(&Vec::new([z]))
+        .ok_or(FitError::Singular)
+}
+
+/// Evaluates the polynomial `c[0] + c[1] x + c[2] x² + ...` at `x` with Horner's
+/// method.
+///
+/// # Example
+/// ```
+/// use mats::evaluate_polynomial;
+///
+/// assert_eq!(evaluate_polynomial(&[1.0, 0.0, 2.0], 3.0), 19.0);
+/// assert_eq!(evaluate_polynomial::<f32>(&[], 3.0), 0.0);
+/// ```
+#[inline]
+pub fn evaluate_polynomial<T: Float>(coefficients: &[T], x: T) -> T {
+    coefficients
+        .iter()
+        .rev()
+        .fold(T::zero(), |acc, &c| acc * x + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_quadratic() {
+        let xs = [-1.0, 0.5, 2.0];
+        let ys = xs.map(|x: f64| 3.0 - x + 0.5 * x * x);
+
+        let c = fit_polynomial::<3, 3, f64>(&xs, &ys).unwrap();
+        let error = c - Vec::new([[3.0, -1.0, 0.5]]);
+        assert!(error.inner_product(&error) < 1e-24);
+    }
+
+    #[test]
+    fn test_noisy_line() {
+        let xs: [f64; 9] = core::array::from_fn(|i| i as f64);
+        // Alternating noise cancels out in the sums, but is not zero at any point.
+        let noise = [0.1, -0.1, 0.1, -0.1, 0.0, 0.1, -0.1, 0.1, -0.1];
+        let ys: [f64; 9] = core::array::from_fn(|i| 2.0 * xs[i] - 1.0 + noise[i]);
+
+        let [[b, m]] = fit_polynomial::<2, 9, f64>(&xs, &ys)
+            .unwrap()
+            .to_cols_array_2d();
+        assert!((m - 2.0).abs() < 0.02, "slope {m}");
+        assert!((b + 1.0).abs() < 0.1, "intercept {b}");
+    }
+
+    #[test]
+    fn test_badly_scaled_cubic() {
+        // Far from the origin the columns of the Vandermonde matrix are nearly
+        // parallel; the normal equations would square their condition number.
+        let xs: [f64; 12] = core::array::from_fn(|i| 1000.0 + i as f64 * 0.5);
+        let c = [2.0, -0.5, 1e-3, 2e-6];
+        let ys = xs.map(|x| evaluate_polynomial(&c, x));
+
+        let fit = fit_polynomial::<4, 12, f64>(&xs, &ys).unwrap();
+        for &x in &xs {
+            let error = evaluate_polynomial(fit.as_ref(), x) - evaluate_polynomial(&c, x);
+            assert!(error.abs() < 1e-6, "error {error} at {x}");
+        }
+    }
+
+    #[test]
+    fn test_slice_matches_array() {
+        let xs: [f64; 12] = core::array::from_fn(|i| 1000.0 + i as f64 * 0.5);
+        let c = [2.0, -0.5, 1e-3, 2e-6];
+        let noise = |i: usize| if i.is_multiple_of(2) { 1e-3 } else { -1e-3 };
+        let ys: [f64; 12] = core::array::from_fn(|i| evaluate_polynomial(&c, xs[i]) + noise(i));
+
+        let array = fit_polynomial::<4, 12, f64>(&xs, &ys).unwrap();
+        let slice = fit_polynomial_slice::<4, f64>(&xs, &ys).unwrap();
+        for &x in &xs {
+            let error =
+                evaluate_polynomial(slice.as_ref(), x) - evaluate_polynomial(array.as_ref(), x);
+            assert!(error.abs() < 1e-6, "error {error} at {x}");
+        }
+
+        // Rows with `x == 0` skip the rotations of the higher powers.
+        let c = fit_polynomial_slice::<3, f64>(&[0.0, 1.0, -1.0, 0.0], &[1.0, 2.0, 2.0, 1.0]);
+        let error = c.unwrap() - Vec::new([[1.0, 0.0, 1.0]]);
+        assert!(error.inner_product(&error) < 1e-24);
+    }
+
+    #[test]
+    fn test_fit_errors() {
+        assert_eq!(
+            fit_polynomial::<3, 2, f32>(&[0.0, 1.0], &[0.0, 1.0]),
+            Err(FitError::TooFewPoints)
+        );
+        assert_eq!(
+            fit_polynomial::<2, 3, f32>(&[1.0, 1.0, 1.0], &[0.0, 1.0, 2.0]),
+            Err(FitError::Singular)
+        );
+        assert_eq!(
+            fit_polynomial_slice::<2, f32>(&[0.0, 1.0, 2.0], &[0.0, 1.0]),
+            Err(FitError::LengthMismatch)
+        );
+        assert_eq!(
+            fit_polynomial_slice::<3, f32>(&[0.0, 1.0], &[0.0, 1.0]),
+            Err(FitError::TooFewPoints)
+        );
+        assert_eq!(
+            fit_polynomial_slice::<2, f32>(&[1.0, 1.0, 1.0], &[0.0, 1.0, 2.0]),
+            Err(FitError::Singular)
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(
+            FitError::TooFewPoints.to_string(),
+            "need at least as many points as coefficients"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crate::Mat;
+
+/// The format of a single vertex attribute location.
+///
+/// The variants follow the naming of `wgpu::VertexFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VertexFormat {
+    Float32x2,
+    Float32x3,
+    Float32x4,
+    Float64x2,
+    Float64x3,
+    Float64x4,
+    Sint32x2,
+    Sint32x3,
+    Sint32x4,
+    Uint32x2,
+    Uint32x3,
+    Uint32x4,
+}
+
+impl VertexFormat {
+    /// Returns the number of components of the format.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::gpu::VertexFormat;
+    ///
+    /// assert_eq!(VertexFormat::Float32x3.components(), 3);
+    /// ```
+    #[inline]
+    pub const fn components(self) -> usize {
+        match self {
+            Self::Float32x2 | Self::Float64x2 | Self::Sint32x2 | Self::Uint32x2 => 2,
+            Self::Float32x3 | Self::Float64x3 | Self::Sint32x3 | Self::Uint32x3 => 3,
+            Self::Float32x4 | Self::Float64x4 | Self::Sint32x4 | Self::Uint32x4 => 4,
+        }
+    }
+
+    /// Returns the size of a single component in bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::gpu::VertexFormat;
+    ///
+    /// assert_eq!(VertexFormat::Float64x2.component_size(), 8);
+    /// ```
+    #[inline]
+    pub const fn component_size(self) -> usize {
+        match self {
+            Self::Float64x2 | Self::Float64x3 | Self::Float64x4 => 8,
+            _ => 4,
+        }
+    }
+
+    /// Returns the size of the format in bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::gpu::VertexFormat;
+    ///
+    /// assert_eq!(VertexFormat::Float32x3.size(), 12);
+    /// ```
+    #[inline]
+    pub const fn size(self) -> usize {
+        self.components() * self.component_size()
+    }
+}
+
+/// A trait for types that can be used as vertex attributes.
+///
+/// A matrix `Mat<ROWS, COLS, T>` occupies `COLS` consecutive attribute locations,
+/// one per column, each with the format of `Vec<ROWS, T>`.
+///
+/// # Example
+/// ```
+/// use mats::{Mat4, Vec3};
+/// use mats::gpu::{VertexAttribute, VertexFormat};
+///
+/// assert_eq!(Vec3::<f32>::GPU_FORMAT, VertexFormat::Float32x3);
+/// assert_eq!(Vec3::<f32>::LOCATIONS, 1);
+///
+/// assert_eq!(Mat4::<f32>::GPU_FORMAT, VertexFormat::Float32x4);
+/// assert_eq!(Mat4::<f32>::LOCATIONS, 4);
+/// ```
+pub trait VertexAttribute {
+    /// The format of each attribute location.
+    const GPU_FORMAT: VertexFormat;
+    /// The number of attribute locations the type occupies.
+    const LOCATIONS: usize;
+    /// The size of the type in bytes.
+    const SIZE: usize = Self::GPU_FORMAT.size() * Self::LOCATIONS;
+}
+
+macro_rules! impl_vertex_attribute {
+    ($($t:ty => [$f2:ident, $f3:ident, $f4:ident]),*) => {
+        $(
+            impl<const COLS: usize> VertexAttribute for Mat<2, COLS, $t> {
+                const GPU_FORMAT: VertexFormat = VertexFormat::$f2;
+                const LOCATIONS: usize = COLS;
+            }
+
+            impl<const COLS: usize> VertexAttribute for Mat<3, COLS, $t> {
+                const GPU_FORMAT: VertexFormat = VertexFormat::$f3;
+                const LOCATIONS: usize = COLS;
+            }
+
+            impl<const COLS: usize> VertexAttribute for Mat<4, COLS, $t> {
+                const GPU_FORMAT: VertexFormat = VertexFormat::$f4;
+                const LOCATIONS: usize = COLS;
+            }
+        )*
+    };
+}
+
+impl_vertex_attribute!(
+    f32 => [Float32x2, Float32x3, Float32x4],
+    f64 => [Float64x2, Float64x3, Float64x4],
+    i32 => [Sint32x2, Sint32x3, Sint32x4],
+    u32 => [Uint32x2, Uint32x3, Uint32x4]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Mat3, Mat4, Vec2, Vec3, Vec4};
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn test_sizes_match_layout() {
+        assert_eq!(size_of::<Vec2<f32>>(), 8);
+        assert_eq!(size_of::<Vec3<f32>>(), 12);
+        assert_eq!(size_of::<Vec4<f32>>(), 16);
+        assert_eq!(size_of::<Mat3<f32>>(), 36);
+        assert_eq!(size_of::<Mat4<f64>>(), 128);
+        assert_eq!(align_of::<Vec3<f32>>(), align_of::<f32>());
+
+        assert_eq!(Vec2::<f32>::SIZE, size_of::<Vec2<f32>>());
+        assert_eq!(Vec3::<f32>::SIZE, size_of::<Vec3<f32>>());
+        assert_eq!(Vec4::<u32>::SIZE, size_of::<Vec4<u32>>());
+        assert_eq!(Vec3::<f64>::SIZE, size_of::<Vec3<f64>>());
+        assert_eq!(Mat3::<f32>::SIZE, size_of::<Mat3<f32>>());
+        assert_eq!(Mat4::<f32>::SIZE, size_of::<Mat4<f32>>());
+    }
+
+    #[test]
+    fn test_formats() {
+        assert_eq!(Vec2::<f32>::GPU_FORMAT, VertexFormat::Float32x2);
+        assert_eq!(Vec4::<f64>::GPU_FORMAT, VertexFormat::Float64x4);
+        assert_eq!(Vec3::<i32>::GPU_FORMAT, VertexFormat::Sint32x3);
+        assert_eq!(Vec2::<u32>::GPU_FORMAT, VertexFormat::Uint32x2);
+        assert_eq!(Mat3::<f32>::GPU_FORMAT, VertexFormat::Float32x3);
+        assert_eq!(Mat3::<f32>::LOCATIONS, 3);
+    }
+}
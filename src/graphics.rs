@@ -1,4 +1,12 @@
-use crate::{Float, Mat, Mat3, Mat4, Vec2, Vec3};
+use core::cmp::Ordering;
+
+use crate::{Angle, Float, Mat, Mat2, Mat3, Mat4, Rad, Vec2, Vec3, Vec4};
+
+/// Module `color` converts colors stored in [`Vec3`] between sRGB, linear RGB and
+/// HSV, with [`Vec4`] variants that pass alpha through.
+///
+/// All components, including the HSV hue, are in `[0, 1]`.
+pub mod color;
 
 /// Create a 2D scaling matrix.
 ///
@@ -48,6 +56,59 @@ pub fn translate2d<T: Float>(v: Vec2<T>) -> Mat3<T> {
     }
 }
 
+/// Embed a 2x2 linear transformation into a homogeneous 3x3 matrix.
+#[inline]
+fn homogeneous2<T: Float>(m: Mat2<T>) -> Mat3<T> {
+    let [c0, c1] = m.data;
+    Mat {
+        data: [
+            [c0[0], c0[1], T::zero()],
+            [c1[0], c1[1], T::zero()],
+            [T::zero(), T::zero(), T::unit_one()],
+        ],
+    }
+}
+
+/// Embed a 3x3 linear transformation into a homogeneous 4x4 matrix.
+#[inline]
+fn homogeneous3<T: Float>(m: Mat3<T>) -> Mat4<T> {
+    let [c0, c1, c2] = m.data;
+    Mat {
+        data: [
+            [c0[0], c0[1], c0[2], T::zero()],
+            [c1[0], c1[1], c1[2], T::zero()],
+            [c2[0], c2[1], c2[2], T::zero()],
+            [T::zero(), T::zero(), T::zero(), T::unit_one()],
+        ],
+    }
+}
+
+/// Create a 2D rotation matrix for direction vectors.
+///
+/// This is the linear part of [`rotate2d`].
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::rotation2};
+///
+/// let v = Vec2::new([[1.0, 0.0]]);
+/// let v2 = rotation2(std::f32::consts::PI / 2.0) * v;
+///
+/// let ais = v2 - Vec2::new([[0.0, 1.0]]);
+///
+/// assert!(ais.x().abs() <= f32::EPSILON);
+/// assert!(ais.y().abs() <= f32::EPSILON);
+/// ```
+#[inline]
+pub fn rotation2<T: Float>(angle: impl Angle<T>) -> Mat2<T> {
+    let Rad(angle) = angle.to_rad();
+    let c = angle.cos();
+    let s = angle.sin();
+    Mat {
+        data: [[c, s], [-s, c]],
+    }
+}
+
 /// Create a 2D rotation matrix.
 ///
 /// # Example
@@ -64,16 +125,8 @@ pub fn translate2d<T: Float>(v: Vec2<T>) -> Mat3<T> {
 /// assert!(ais.y().abs() <= f32::EPSILON);
 /// ```
 #[inline]
-pub fn rotate2d<T: Float>(angle: T) -> Mat3<T> {
-    let c = angle.cos();
-    let s = angle.sin();
-    Mat {
-        data: [
-            [c, s, T::zero()],
-            [-s, c, T::zero()],
-            [T::zero(), T::zero(), T::unit_one()],
-        ],
-    }
+pub fn rotate2d<T: Float>(angle: impl Angle<T>) -> Mat3<T> {
+    homogeneous2(rotation2(angle))
 }
 
 /// Create a 3D translation matrix.
@@ -128,6 +181,112 @@ pub fn scale3d<T: Float>(v: Vec3<T>) -> Mat4<T> {
     }
 }
 
+/// Create the matrix mirroring space across the `yz` plane, negating `x`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec4, graphics::mirror_x};
+///
+/// let m = mirror_x::<f64>();
+///
+/// assert_eq!(m * Vec4::new([[1.0, 2.0, 3.0, 1.0]]), Vec4::new([[-1.0, 2.0, 3.0, 1.0]]));
+/// ```
+#[inline]
+pub fn mirror_x<T: Float>() -> Mat4<T> {
+    let i = T::unit_one();
+    scale3d(Vec3::new([[-i, i, i]]))
+}
+
+/// Create the matrix mirroring space across the `xz` plane, negating `y`.
+#[inline]
+pub fn mirror_y<T: Float>() -> Mat4<T> {
+    let i = T::unit_one();
+    scale3d(Vec3::new([[i, -i, i]]))
+}
+
+/// Create the matrix mirroring space across the `xy` plane, negating `z`.
+#[inline]
+pub fn mirror_z<T: Float>() -> Mat4<T> {
+    let i = T::unit_one();
+    scale3d(Vec3::new([[i, i, -i]]))
+}
+
+/// Create the matrix converting between left- and right-handed coordinates with the
+/// same `x` right and `y` up axes, by negating `z`.
+///
+/// This is [`mirror_z`], its own inverse, and turns e.g. a left-handed `+z` forward
+/// convention into the right-handed `-z` forward of [`look_at`]. It flips the winding
+/// order of triangles.
+#[inline]
+pub fn flip_handedness<T: Float>() -> Mat4<T> {
+    mirror_z()
+}
+
+/// Create the matrix converting right-handed `z`-up coordinates (`x` right, `y`
+/// forward, `z` up) to right-handed `y`-up coordinates (`x` right, `y` up, `z`
+/// backward), mapping `(x, y, z)` to `(x, z, -y)`.
+///
+/// It is a rotation by -90° around `x`, so it keeps the winding order of triangles.
+///
+/// # Example
+/// ```
+/// use mats::{Vec4, graphics::z_up_to_y_up};
+///
+/// let up = Vec4::new([[0.0, 0.0, 1.0, 0.0]]);
+/// let forward = Vec4::new([[0.0, 1.0, 0.0, 0.0]]);
+///
+/// let m = z_up_to_y_up::<f64>();
+///
+/// assert_eq!(m * up, Vec4::new([[0.0, 1.0, 0.0, 0.0]]));
+/// assert_eq!(m * forward, Vec4::new([[0.0, 0.0, -1.0, 0.0]]));
+/// ```
+#[inline]
+pub fn z_up_to_y_up<T: Float>() -> Mat4<T> {
+    let (o, i) = (T::zero(), T::unit_one());
+    Mat {
+        data: [[i, o, o, o], [o, o, -i, o], [o, i, o, o], [o, o, o, i]],
+    }
+}
+
+/// Create the matrix converting right-handed `y`-up coordinates to right-handed
+/// `z`-up coordinates, mapping `(x, y, z)` to `(x, -z, y)`; the inverse of
+/// [`z_up_to_y_up`].
+#[inline]
+pub fn y_up_to_z_up<T: Float>() -> Mat4<T> {
+    z_up_to_y_up().T()
+}
+
+/// Create a 3D rotation matrix around the X axis for direction vectors.
+///
+/// This is the linear part of [`rotate3d_x`].
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::rotation3_x};
+///
+/// let v = Vec3::new([[0.0, 1.0, 0.0]]);
+/// let v2 = rotation3_x(std::f32::consts::PI / 2.0) * v;
+///
+/// let ais = v2 - Vec3::new([[0.0, 0.0, 1.0]]);
+///
+/// assert!(ais.x().abs() <= f32::EPSILON);
+/// assert!(ais.y().abs() <= f32::EPSILON);
+/// assert!(ais.z().abs() <= f32::EPSILON);
+/// ```
+#[inline]
+pub fn rotation3_x<T: Float>(angle: impl Angle<T>) -> Mat3<T> {
+    let Rad(angle) = angle.to_rad();
+    let c = angle.cos();
+    let s = angle.sin();
+    Mat {
+        data: [
+            [T::unit_one(), T::zero(), T::zero()],
+            [T::zero(), c, s],
+            [T::zero(), -s, c],
+        ],
+    }
+}
+
 /// Create a 3D rotation matrix around the X axis.
 ///
 /// # Example
@@ -145,15 +304,37 @@ pub fn scale3d<T: Float>(v: Vec3<T>) -> Mat4<T> {
 /// assert!(ais.z().abs() <= f32::EPSILON);
 /// ```
 #[inline]
-pub fn rotate3d_x<T: Float>(angle: T) -> Mat4<T> {
+pub fn rotate3d_x<T: Float>(angle: impl Angle<T>) -> Mat4<T> {
+    homogeneous3(rotation3_x(angle))
+}
+
+/// Create a 3D rotation matrix around the Y axis for direction vectors.
+///
+/// This is the linear part of [`rotate3d_y`].
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::rotation3_y};
+///
+/// let v = Vec3::new([[1.0, 0.0, 0.0]]);
+/// let v2 = rotation3_y(std::f32::consts::PI / 2.0) * v;
+///
+/// let ais = v2 - Vec3::new([[0.0, 0.0, -1.0]]);
+///
+/// assert!(ais.x().abs() <= f32::EPSILON);
+/// assert!(ais.y().abs() <= f32::EPSILON);
+/// assert!(ais.z().abs() <= f32::EPSILON);
+/// ```
+#[inline]
+pub fn rotation3_y<T: Float>(angle: impl Angle<T>) -> Mat3<T> {
+    let Rad(angle) = angle.to_rad();
     let c = angle.cos();
     let s = angle.sin();
     Mat {
         data: [
-            [T::unit_one(), T::zero(), T::zero(), T::zero()],
-            [T::zero(), c, s, T::zero()],
-            [T::zero(), -s, c, T::zero()],
-            [T::zero(), T::zero(), T::zero(), T::unit_one()],
+            [c, T::zero(), -s],
+            [T::zero(), T::unit_one(), T::zero()],
+            [s, T::zero(), c],
         ],
     }
 }
@@ -175,15 +356,37 @@ pub fn rotate3d_x<T: Float>(angle: T) -> Mat4<T> {
 /// assert!(ais.z().abs() <= f32::EPSILON);
 /// ```
 #[inline]
-pub fn rotate3d_y<T: Float>(angle: T) -> Mat4<T> {
+pub fn rotate3d_y<T: Float>(angle: impl Angle<T>) -> Mat4<T> {
+    homogeneous3(rotation3_y(angle))
+}
+
+/// Create a 3D rotation matrix around the Z axis for direction vectors.
+///
+/// This is the linear part of [`rotate3d_z`].
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::rotation3_z};
+///
+/// let v = Vec3::new([[1.0, 0.0, 0.0]]);
+/// let v2 = rotation3_z(std::f32::consts::PI / 2.0) * v;
+///
+/// let ais = v2 - Vec3::new([[0.0, 1.0, 0.0]]);
+///
+/// assert!(ais.x().abs() <= f32::EPSILON);
+/// assert!(ais.y().abs() <= f32::EPSILON);
+/// assert!(ais.z().abs() <= f32::EPSILON);
+/// ```
+#[inline]
+pub fn rotation3_z<T: Float>(angle: impl Angle<T>) -> Mat3<T> {
+    let Rad(angle) = angle.to_rad();
     let c = angle.cos();
     let s = angle.sin();
     Mat {
         data: [
-            [c, T::zero(), -s, T::zero()],
-            [T::zero(), T::unit_one(), T::zero(), T::zero()],
-            [s, T::zero(), c, T::zero()],
-            [T::zero(), T::zero(), T::zero(), T::unit_one()],
+            [c, s, T::zero()],
+            [-s, c, T::zero()],
+            [T::zero(), T::zero(), T::unit_one()],
         ],
     }
 }
@@ -205,21 +408,85 @@ pub fn rotate3d_y<T: Float>(angle: T) -> Mat4<T> {
 /// assert!(ais.z().abs() <= f32::EPSILON);
 /// ```
 #[inline]
-pub fn rotate3d_z<T: Float>(angle: T) -> Mat4<T> {
+pub fn rotate3d_z<T: Float>(angle: impl Angle<T>) -> Mat4<T> {
+    homogeneous3(rotation3_z(angle))
+}
+
+/// Create a 3D rotation matrix around `axis` for direction vectors.
+///
+/// This is the linear part of [`rotate3d`]; `axis` is normalized first. An axis
+/// shorter than `T::EPSILON` has no direction, and gives the identity matrix, as
+/// does an axis whose length is not finite; use [`try_rotation3`] to detect it.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::rotation3};
+///
+/// let v = Vec3::new([[1.0, 0.0, 0.0]]);
+/// let v2 = rotation3(Vec3::new([[0.0, 2.0, 0.0]]), std::f32::consts::PI / 2.0) * v;
+///
+/// let ais = v2 - Vec3::new([[0.0, 0.0, -1.0]]);
+///
+/// assert!(ais.x().abs() <= f32::EPSILON);
+/// assert!(ais.y().abs() <= f32::EPSILON);
+/// assert!(ais.z().abs() <= f32::EPSILON);
+/// ```
+#[inline]
+pub fn rotation3<T: Float>(axis: Vec3<T>, angle: impl Angle<T>) -> Mat3<T> {
+    try_rotation3(axis, angle).unwrap_or_else(Mat3::I)
+}
+
+/// Create a 3D rotation matrix around `axis` for direction vectors, or `None` if
+/// `axis` is shorter than `T::EPSILON` or its length is not finite.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::try_rotation3};
+///
+/// assert!(try_rotation3(Vec3::new([[0.0, 0.0, 1.0]]), 1.0).is_some());
+/// assert!(try_rotation3(Vec3::new([[0.0, 0.0, 0.0]]), 1.0).is_none());
+/// ```
+#[inline]
+pub fn try_rotation3<T: Float>(axis: Vec3<T>, angle: impl Angle<T>) -> Option<Mat3<T>> {
+    let len = axis.inner_product(&axis).sqrt();
+    (len >= T::EPSILON && len.is_finite()).then(|| rotation3_no_norm(axis / len, angle.to_rad()))
+}
+
+/// Rodrigues' rotation formula around the unit vector `axis`.
+#[inline]
+fn rotation3_no_norm<T: Float>(axis: Vec3<T>, Rad(angle): Rad<T>) -> Mat3<T> {
+    let xx = axis.x() * axis.x();
+    let yy = axis.y() * axis.y();
+    let zz = axis.z() * axis.z();
+    // values
     let c = angle.cos();
     let s = angle.sin();
+    let c1 = T::unit_one() - c;
+    let xyc1 = axis.x() * axis.y() * c1;
+    let yzc1 = axis.y() * axis.z() * c1;
+    let xzc1 = axis.x() * axis.z() * c1;
+    let xs = axis.x() * s;
+    let ys = axis.y() * s;
+    let zs = axis.z() * s;
+    let xxc1 = xx * c1;
+    let yyc1 = yy * c1;
+    let zzc1 = zz * c1;
+    // result
     Mat {
         data: [
-            [c, s, T::zero(), T::zero()],
-            [-s, c, T::zero(), T::zero()],
-            [T::zero(), T::zero(), T::unit_one(), T::zero()],
-            [T::zero(), T::zero(), T::zero(), T::unit_one()],
+            [c + xxc1, xyc1 + zs, xzc1 - ys],
+            [xyc1 - zs, c + yyc1, yzc1 + xs],
+            [xzc1 + ys, yzc1 - xs, c + zzc1],
         ],
     }
 }
 
 /// Create a 3D rotation matrix around `axis`.
 ///
+/// `axis` is normalized first. An axis shorter than `T::EPSILON` has no direction,
+/// and gives the identity matrix instead of NaN, as does an axis whose length is
+/// not finite; use [`try_rotate3d`] to detect it.
+///
 /// # Example
 /// ```
 /// use mats::{Vec4, Vec3, graphics::rotate3d};
@@ -235,7 +502,7 @@ pub fn rotate3d_z<T: Float>(angle: T) -> Mat4<T> {
 /// assert!(ais.z().abs() <= f32::EPSILON);
 /// ```
 #[inline]
-pub fn rotate3d<T: Float>(axis: Vec3<T>, angle: T) -> Mat4<T> {
+pub fn rotate3d<T: Float>(axis: Vec3<T>, angle: impl Angle<T>) -> Mat4<T> {
     // Diary: Fix this function's BUG.
     //
     // For performance reasons, I try to minimize multiplication
@@ -277,14 +544,29 @@ pub fn rotate3d<T: Float>(axis: Vec3<T>, angle: T) -> Mat4<T> {
     // }
 
     // So now it's implemented like this.
-    let axis = axis / axis.T().dot(&axis)[0].sqrt();
-    unsafe { rotate3d_no_norm(axis, angle) }
+    try_rotate3d(axis, angle).unwrap_or_else(Mat4::I)
+}
+
+/// Create a 3D rotation matrix around `axis`, or `None` if `axis` is shorter than
+/// `T::EPSILON` or its length is not finite.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::try_rotate3d};
+///
+/// assert!(try_rotate3d(Vec3::new([[0.0, 2.0, 0.0]]), 1.0).is_some());
+/// assert!(try_rotate3d(Vec3::new([[0.0, 0.0, 0.0]]), 1.0).is_none());
+/// ```
+#[inline]
+pub fn try_rotate3d<T: Float>(axis: Vec3<T>, angle: impl Angle<T>) -> Option<Mat4<T>> {
+    try_rotation3(axis, angle).map(homogeneous3)
 }
 
 /// Create a 3D rotation matrix around `axis` without normalizing it.
 ///
 /// # Safety
-/// This function does not check if `axis` is normalized.
+/// This function does not check if `axis` is normalized. In debug builds, a zero
+/// `axis` panics instead of producing a degenerate matrix.
 ///
 /// # Example
 /// ```
@@ -301,36 +583,24 @@ pub fn rotate3d<T: Float>(axis: Vec3<T>, angle: T) -> Mat4<T> {
 /// assert!(ais.z().abs() <= f32::EPSILON);
 /// ```
 #[inline]
-pub unsafe fn rotate3d_no_norm<T: Float>(axis: Vec3<T>, angle: T) -> Mat4<T> {
-    let xx = axis.x() * axis.x();
-    let yy = axis.y() * axis.y();
-    let zz = axis.z() * axis.z();
-    // values
-    let c = angle.cos();
-    let s = angle.sin();
-    let c1 = T::unit_one() - c;
-    let xyc1 = axis.x() * axis.y() * c1;
-    let yzc1 = axis.y() * axis.z() * c1;
-    let xzc1 = axis.x() * axis.z() * c1;
-    let xs = axis.x() * s;
-    let ys = axis.y() * s;
-    let zs = axis.z() * s;
-    let xxc1 = xx * c1;
-    let yyc1 = yy * c1;
-    let zzc1 = zz * c1;
-    // result
-    Mat {
-        data: [
-            [c + xxc1, xyc1 + zs, xzc1 - ys, T::zero()],
-            [xyc1 - zs, c + yyc1, yzc1 + xs, T::zero()],
-            [xzc1 + ys, yzc1 - xs, c + zzc1, T::zero()],
-            [T::zero(), T::zero(), T::zero(), T::unit_one()],
-        ],
-    }
+pub unsafe fn rotate3d_no_norm<T: Float>(axis: Vec3<T>, angle: impl Angle<T>) -> Mat4<T> {
+    debug_assert!(
+        axis.inner_product(&axis) >= T::EPSILON * T::EPSILON,
+        "rotation axis must not be zero"
+    );
+    homogeneous3(rotation3_no_norm(axis, angle.to_rad()))
 }
 
 /// Create a view transformation matrix.
 ///
+/// This is the world-to-camera transform: it maps world coordinates into a
+/// right-handed camera space with the camera at the origin, looking down `-z`, with
+/// `+y` up. [`look_at_inverse`] is the camera-to-world transform.
+///
+/// # Panics
+/// With the `strict-math` feature, debug builds panic if the result is not finite,
+/// e.g. when `eye == center` or `up` is parallel to the view direction.
+///
 /// # Example
 /// ```
 /// use mats::{Vec3, Vec4, graphics::look_at};
@@ -346,9 +616,90 @@ pub unsafe fn rotate3d_no_norm<T: Float>(axis: Vec3<T>, angle: T) -> Mat4<T> {
 /// assert_eq!(v2.x(), 1.0);
 /// assert_eq!(v2.y(), 0.0);
 /// assert_eq!(v2.z(), 0.0);
+///
+/// // A camera at +x looking at the origin sees the world point (1, 2, 3)
+/// // 3 units to its left, 2 units up and 2 units in front of it.
+/// let view = look_at(Vec3::new([[3.0, 0.0, 0.0]]), Vec3::new([[0.0, 0.0, 0.0]]), up);
+///
+/// assert_eq!(view * Vec4::new([[1.0, 2.0, 3.0, 1.0]]), Vec4::new([[-3.0, 2.0, -2.0, 1.0]]));
 /// ```
 #[inline]
 pub fn look_at<T: Float>(eye: Vec3<T>, center: Vec3<T>, up: Vec3<T>) -> Mat4<T> {
+    let (x, y, z) = camera_basis(eye, center, up);
+
+    let a = -x.inner_product(&eye);
+    let b = -y.inner_product(&eye);
+    let c = -z.inner_product(&eye);
+    let view = Mat {
+        data: [
+            [x.x(), y.x(), z.x(), T::zero()],
+            [x.y(), y.y(), z.y(), T::zero()],
+            [x.z(), y.z(), z.z(), T::zero()],
+            [a, b, c, T::unit_one()],
+        ],
+    };
+    if cfg!(all(feature = "strict-math", debug_assertions)) {
+        view.assert_finite("look_at");
+    }
+    view
+}
+
+/// Create a view transformation matrix for a camera at `eye` looking along
+/// `direction`, the same as `look_at(eye, eye + direction, up)`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::{look_at, look_to}};
+///
+/// let eye = Vec3::new([[1.0, 2.0, 3.0]]);
+/// let direction = Vec3::new([[0.0, 0.0, -2.0]]);
+/// let up = Vec3::new([[0.0, 1.0, 0.0]]);
+///
+/// assert_eq!(look_to(eye, direction, up), look_at(eye, eye + direction, up));
+/// ```
+#[inline]
+pub fn look_to<T: Float>(eye: Vec3<T>, direction: Vec3<T>, up: Vec3<T>) -> Mat4<T> {
+    look_at(eye, eye + direction, up)
+}
+
+/// Create the camera-to-world transformation matrix, the inverse of [`look_at`].
+///
+/// Its columns are the camera's right, up and backward axes and its position, so it
+/// can be used as the model matrix of an object placed at the camera.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, Vec4, graphics::look_at_inverse};
+///
+/// let eye = Vec3::new([[3.0, 0.0, 0.0]]);
+/// let center = Vec3::new([[0.0, 0.0, 0.0]]);
+/// let up = Vec3::new([[0.0, 1.0, 0.0]]);
+/// let camera = look_at_inverse(eye, center, up);
+///
+/// // The camera space origin is the eye, and -z points at the center.
+/// assert_eq!(camera * Vec4::new([[0.0, 0.0, 0.0, 1.0]]), Vec4::new([[3.0, 0.0, 0.0, 1.0]]));
+/// assert_eq!(camera * Vec4::new([[0.0, 0.0, -3.0, 1.0]]), Vec4::new([[0.0, 0.0, 0.0, 1.0]]));
+/// ```
+#[inline]
+pub fn look_at_inverse<T: Float>(eye: Vec3<T>, center: Vec3<T>, up: Vec3<T>) -> Mat4<T> {
+    let (x, y, z) = camera_basis(eye, center, up);
+    Mat {
+        data: [
+            [x.x(), x.y(), x.z(), T::zero()],
+            [y.x(), y.y(), y.z(), T::zero()],
+            [z.x(), z.y(), z.z(), T::zero()],
+            [eye.x(), eye.y(), eye.z(), T::unit_one()],
+        ],
+    }
+}
+
+/// The orthonormal right, up and backward axes of a camera at `eye` looking at `center`.
+#[inline]
+fn camera_basis<T: Float>(
+    eye: Vec3<T>,
+    center: Vec3<T>,
+    up: Vec3<T>,
+) -> (Vec3<T>, Vec3<T>, Vec3<T>) {
     let z = eye - center;
     let x = up.cross(&z);
     let y = z.cross(&x);
@@ -369,21 +720,49 @@ pub fn look_at<T: Float>(eye: Vec3<T>, center: Vec3<T>, up: Vec3<T>) -> Mat4<T>
     let zz = y.z() * y.z();
     let y = y / (xx + yy + zz).sqrt();
 
-    let a = -x.T().dot(&eye)[0];
-    let b = -y.T().dot(&eye)[0];
-    let c = -z.T().dot(&eye)[0];
-    Mat {
-        data: [
-            [x.x(), y.x(), z.x(), T::zero()],
-            [x.y(), y.y(), z.y(), T::zero()],
-            [x.z(), y.z(), z.z(), T::zero()],
-            [a, b, c, T::unit_one()],
-        ],
+    (x, y, z)
+}
+
+/// The error returned by the `try_` projection constructors, naming the parameter
+/// that makes the projection degenerate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectionError {
+    /// The field of view is not in the open interval `(0, π)`.
+    FieldOfView,
+    /// The aspect ratio is not positive.
+    AspectRatio,
+    /// The near plane of a perspective projection is not positive.
+    NearPlane,
+    /// The near and far planes coincide, or one of them is not finite in an
+    /// orthographic projection.
+    DepthRange,
+    /// The left and right planes coincide or are not finite.
+    Width,
+    /// The top and bottom planes coincide or are not finite.
+    Height,
+}
+
+impl core::fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::FieldOfView => "field of view must be in (0, π)",
+            Self::AspectRatio => "aspect ratio must be positive",
+            Self::NearPlane => "near plane must be positive",
+            Self::DepthRange => "near and far planes must differ",
+            Self::Width => "left and right planes must be finite and differ",
+            Self::Height => "top and bottom planes must be finite and differ",
+        })
     }
 }
 
+impl core::error::Error for ProjectionError {}
+
 /// Create a perspective projection matrix.
 ///
+/// # Panics
+/// In debug builds, panics if the parameters are invalid, see [`try_perspective`].
+/// With the `strict-math` feature, debug builds also panic if the result is not finite.
+///
 /// # Example
 /// ```
 /// use mats::{Vec4, graphics::perspective};
@@ -401,7 +780,68 @@ pub fn look_at<T: Float>(eye: Vec3<T>, center: Vec3<T>, up: Vec3<T>) -> Mat4<T>
 /// assert_eq!(v2.y(), 0.0);
 /// assert_eq!(v2.z(), -200.0 / 999.0);
 /// ```
-pub fn perspective<T: Float>(fov: T, aspect: T, z_near: T, z_far: T) -> Mat4<T> {
+pub fn perspective<T: Float>(fov: impl Angle<T>, aspect: T, z_near: T, z_far: T) -> Mat4<T> {
+    let Rad(fov) = fov.to_rad();
+    if cfg!(debug_assertions)
+        && let Err(e) = check_perspective(fov, aspect, z_near, z_far)
+    {
+        panic!("invalid perspective projection: {e}");
+    }
+    let proj = perspective_unchecked(fov, aspect, z_near, z_far);
+    if cfg!(all(feature = "strict-math", debug_assertions)) {
+        proj.assert_finite("perspective");
+    }
+    proj
+}
+
+/// Create a perspective projection matrix, checking that it is not degenerate.
+///
+/// # Errors
+/// Returns the [`ProjectionError`] naming the first invalid parameter: `fov` must be
+/// in `(0, π)`, `aspect` and `z_near` must be positive, and `z_near != z_far`.
+///
+/// # Example
+/// ```
+/// use mats::graphics::{ProjectionError, perspective, try_perspective};
+///
+/// assert_eq!(try_perspective(1.0, 1.5, 0.1, 100.0), Ok(perspective(1.0, 1.5, 0.1, 100.0)));
+/// assert_eq!(try_perspective(1.0, 0.0, 0.1, 100.0), Err(ProjectionError::AspectRatio));
+/// ```
+pub fn try_perspective<T: Float>(
+    fov: impl Angle<T>,
+    aspect: T,
+    z_near: T,
+    z_far: T,
+) -> Result<Mat4<T>, ProjectionError> {
+    let Rad(fov) = fov.to_rad();
+    check_perspective(fov, aspect, z_near, z_far)?;
+    Ok(perspective_unchecked(fov, aspect, z_near, z_far))
+}
+
+/// Written so that NaN parameters fail the checks too.
+fn check_perspective<T: Float>(
+    fov: T,
+    aspect: T,
+    z_near: T,
+    z_far: T,
+) -> Result<(), ProjectionError> {
+    let positive = |x: T| x.partial_cmp(&T::zero()) == Some(Ordering::Greater);
+    if !positive(fov) || !positive(T::PI - fov) {
+        return Err(ProjectionError::FieldOfView);
+    }
+    if !positive(aspect) {
+        return Err(ProjectionError::AspectRatio);
+    }
+    if !positive(z_near) {
+        return Err(ProjectionError::NearPlane);
+    }
+    if z_near == z_far {
+        return Err(ProjectionError::DepthRange);
+    }
+    Ok(())
+}
+
+fn perspective_unchecked<T: Float>(fov: T, aspect: T, z_near: T, z_far: T) -> Mat4<T> {
     let two = T::unit_one() + T::unit_one();
     let f = T::unit_one() / (fov / two).tan();
     let ratio = T::unit_one() / (z_near - z_far);
@@ -420,6 +860,9 @@ pub fn perspective<T: Float>(fov: T, aspect: T, z_near: T, z_far: T) -> Mat4<T>
 
 /// Create an orthographic projection matrix.
 ///
+/// # Panics
+/// In debug builds, panics if the parameters are invalid, see [`try_orthographic`].
+///
 /// # Example
 /// ```
 /// use mats::{Vec4, graphics::orthographic};
@@ -440,6 +883,140 @@ pub fn orthographic<T: Float>(
     (left, top, right, bottom): (T, T, T, T),
     z_near: T,
     z_far: T,
+) -> Mat4<T> {
+    if cfg!(debug_assertions)
+        && let Err(e) = check_orthographic((left, top, right, bottom), z_near, z_far)
+    {
+        panic!("invalid orthographic projection: {e}");
+    }
+    orthographic_unchecked((left, top, right, bottom), z_near, z_far)
+}
+
+/// Create an orthographic projection matrix, checking that it is not degenerate.
+///
+/// # Errors
+/// Returns the [`ProjectionError`] naming the first pair of planes that coincide
+/// or are not finite.
+///
+/// # Example
+/// ```
+/// use mats::graphics::{ProjectionError, orthographic, try_orthographic};
+///
+/// let planes = (-1.0, 1.0, 1.0, -1.0);
+///
+/// assert_eq!(try_orthographic(planes, -10.0, 10.0), Ok(orthographic(planes, -10.0, 10.0)));
+/// assert_eq!(try_orthographic(planes, 1.0, 1.0), Err(ProjectionError::DepthRange));
+/// ```
+pub fn try_orthographic<T: Float>(
+    (left, top, right, bottom): (T, T, T, T),
+    z_near: T,
+    z_far: T,
+) -> Result<Mat4<T>, ProjectionError> {
+    check_orthographic((left, top, right, bottom), z_near, z_far)?;
+    Ok(orthographic_unchecked(
+        (left, top, right, bottom),
+        z_near,
+        z_far,
+    ))
+}
+
+/// Create the 2D transform from pixel coordinates in a `width x height` viewport to
+/// normalized device coordinates.
+///
+/// With `y_down` the pixel origin is the top-left corner and `y` grows downwards,
+/// as window systems and image formats use; otherwise the origin is the bottom-left
+/// corner. Either way `(0, 0)`..`(width, height)` covers `-1..1` on both axes.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::ortho_pixel};
+///
+/// let m = ortho_pixel(800.0, 600.0, true);
+///
+/// assert_eq!(m * Vec3::new([[0.0, 0.0, 1.0]]), Vec3::new([[-1.0, 1.0, 1.0]]));
+/// assert_eq!(m * Vec3::new([[400.0, 600.0, 1.0]]), Vec3::new([[0.0, -1.0, 1.0]]));
+/// ```
+#[inline]
+pub fn ortho_pixel<T: Float>(width: T, height: T, y_down: bool) -> Mat3<T> {
+    let (o, i) = (T::zero(), T::unit_one());
+    let two = i + i;
+    let (sy, ty) = if y_down {
+        (-two / height, i)
+    } else {
+        (two / height, -i)
+    };
+    Mat {
+        data: [[two / width, o, o], [o, sy, o], [-i, ty, i]],
+    }
+}
+
+/// Create the 3D pipeline variant of [`ortho_pixel`], the same as
+/// `orthographic((0, 0, width, height), -1, 1)` with the `top` and `bottom` planes
+/// swapped unless `y_down`.
+///
+/// `z` is mapped to `-z`, so depths in `[-1, 1]` stay visible.
+///
+/// # Example
+/// ```
+/// use mats::{Vec4, graphics::ortho_pixel3d};
+///
+/// let m = ortho_pixel3d(800.0, 600.0, false);
+///
+/// assert_eq!(m * Vec4::new([[800.0, 600.0, 0.5, 1.0]]), Vec4::new([[1.0, 1.0, -0.5, 1.0]]));
+/// ```
+#[inline]
+pub fn ortho_pixel3d<T: Float>(width: T, height: T, y_down: bool) -> Mat4<T> {
+    let o = T::zero();
+    let (top, bottom) = if y_down { (o, height) } else { (height, o) };
+    orthographic_unchecked((o, top, width, bottom), -T::unit_one(), T::unit_one())
+}
+
+/// Create the 2D transform from normalized device coordinates to pixel coordinates
+/// in a viewport of `(width, height)`, the inverse of [`ortho_pixel`].
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::ndc_to_pixel};
+///
+/// let m = ndc_to_pixel((800.0, 600.0), true);
+///
+/// assert_eq!(m * Vec3::new([[0.0, 0.0, 1.0]]), Vec3::new([[400.0, 300.0, 1.0]]));
+/// assert_eq!(m * Vec3::new([[-1.0, 1.0, 1.0]]), Vec3::new([[0.0, 0.0, 1.0]]));
+/// ```
+#[inline]
+pub fn ndc_to_pixel<T: Float>((width, height): (T, T), y_down: bool) -> Mat3<T> {
+    let (o, i) = (T::zero(), T::unit_one());
+    let two = i + i;
+    let (half_width, half_height) = (width / two, height / two);
+    let sy = if y_down { -half_height } else { half_height };
+    Mat {
+        data: [[half_width, o, o], [o, sy, o], [half_width, half_height, i]],
+    }
+}
+
+fn check_orthographic<T: Float>(
+    (left, top, right, bottom): (T, T, T, T),
+    z_near: T,
+    z_far: T,
+) -> Result<(), ProjectionError> {
+    // `is_finite` also rejects NaN, like the comparisons in `check_perspective`.
+    let distinct = |a: T, b: T| a.is_finite() && b.is_finite() && a != b;
+    if !distinct(left, right) {
+        return Err(ProjectionError::Width);
+    }
+    if !distinct(top, bottom) {
+        return Err(ProjectionError::Height);
+    }
+    if !distinct(z_near, z_far) {
+        return Err(ProjectionError::DepthRange);
+    }
+    Ok(())
+}
+
+fn orthographic_unchecked<T: Float>(
+    (left, top, right, bottom): (T, T, T, T),
+    z_near: T,
+    z_far: T,
 ) -> Mat4<T> {
     let two = T::unit_one() + T::unit_one();
 
@@ -457,3 +1034,2137 @@ pub fn orthographic<T: Float>(
         ],
     }
 }
+
+/// A trait for scalar types the 2D orientation predicate can be evaluated on.
+///
+/// Floating-point types evaluate the determinant directly. `i32` and `i64` are
+/// evaluated exactly, without overflow, for their whole range.
+pub trait Orient2d: Copy {
+    /// See [`orient2d`].
+    fn orient2d(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>) -> Ordering;
+}
+
+/// A trait for scalar types the 3D orientation predicate can be evaluated on.
+///
+/// Floating-point types evaluate the determinant directly. `i32` is evaluated
+/// exactly in `i128`.
+pub trait Orient3d: Copy {
+    /// See [`orient3d`].
+    fn orient3d(a: Vec3<Self>, b: Vec3<Self>, c: Vec3<Self>, d: Vec3<Self>) -> Ordering;
+}
+
+impl<T: Float> Orient2d for T {
+    #[inline]
+    fn orient2d(a: Vec2<T>, b: Vec2<T>, c: Vec2<T>) -> Ordering {
+        let det = (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x());
+        det.partial_cmp(&T::zero()).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<T: Float> Orient3d for T {
+    #[inline]
+    fn orient3d(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>, d: Vec3<T>) -> Ordering {
+        let det = (b - a).cross(&(c - a)).inner_product(&(d - a));
+        det.partial_cmp(&T::zero()).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl Orient2d for i32 {
+    #[inline]
+    fn orient2d(a: Vec2<i32>, b: Vec2<i32>, c: Vec2<i32>) -> Ordering {
+        let d = |p: Vec2<i32>, i: usize| p[i] as i128 - a[i] as i128;
+        let det = d(b, 0) * d(c, 1) - d(b, 1) * d(c, 0);
+        det.cmp(&0)
+    }
+}
+
+impl Orient2d for i64 {
+    #[inline]
+    fn orient2d(a: Vec2<i64>, b: Vec2<i64>, c: Vec2<i64>) -> Ordering {
+        // The differences need 65 bits and their products up to 128 bits,
+        // so compare the two products by sign and unsigned magnitude.
+        let d = |p: Vec2<i64>, i: usize| p[i] as i128 - a[i] as i128;
+        let product =
+            |x: i128, y: i128| (x.signum() * y.signum(), x.unsigned_abs() * y.unsigned_abs());
+        let (ls, lm) = product(d(b, 0), d(c, 1));
+        let (rs, rm) = product(d(b, 1), d(c, 0));
+        match ls.cmp(&rs) {
+            Ordering::Equal if ls > 0 => lm.cmp(&rm),
+            Ordering::Equal if ls < 0 => rm.cmp(&lm),
+            order => order,
+        }
+    }
+}
+
+impl Orient3d for i32 {
+    #[inline]
+    fn orient3d(a: Vec3<i32>, b: Vec3<i32>, c: Vec3<i32>, d: Vec3<i32>) -> Ordering {
+        let v = |p: Vec3<i32>| Vec3::new([[0, 1, 2].map(|i| p[i] as i128 - a[i] as i128)]);
+        let det = v(b).cross(&v(c)).inner_product(&v(d));
+        det.cmp(&0)
+    }
+}
+
+/// Determine on which side of the directed line `a -> b` the point `c` lies.
+///
+/// Returns `Greater` if `a`, `b`, `c` are in counterclockwise order (`c` is to the
+/// left), `Less` if they are clockwise (`c` is to the right), and `Equal` if they are
+/// collinear.
+///
+/// # Example
+/// ```
+/// use std::cmp::Ordering;
+/// use mats::{Vec2, graphics::orient2d};
+///
+/// let a = Vec2::new([[0.0, 0.0]]);
+/// let b = Vec2::new([[1.0, 0.0]]);
+///
+/// assert_eq!(orient2d(a, b, Vec2::new([[0.5, 1.0]])), Ordering::Greater);
+/// assert_eq!(orient2d(a, b, Vec2::new([[0.5, -1.0]])), Ordering::Less);
+/// assert_eq!(orient2d(a, b, Vec2::new([[2.0, 0.0]])), Ordering::Equal);
+/// ```
+#[inline]
+pub fn orient2d<T: Orient2d>(a: Vec2<T>, b: Vec2<T>, c: Vec2<T>) -> Ordering {
+    T::orient2d(a, b, c)
+}
+
+/// Determine on which side of the plane through `a`, `b`, `c` the point `d` lies.
+///
+/// Returns `Greater` if `d` lies on the side the normal `(b - a) × (c - a)` points to,
+/// `Less` if it lies on the other side, and `Equal` if the four points are coplanar.
+///
+/// # Example
+/// ```
+/// use std::cmp::Ordering;
+/// use mats::{Vec3, graphics::orient3d};
+///
+/// let a = Vec3::new([[0.0, 0.0, 0.0]]);
+/// let b = Vec3::new([[1.0, 0.0, 0.0]]);
+/// let c = Vec3::new([[0.0, 1.0, 0.0]]);
+///
+/// assert_eq!(orient3d(a, b, c, Vec3::new([[0.0, 0.0, 1.0]])), Ordering::Greater);
+/// assert_eq!(orient3d(a, b, c, Vec3::new([[0.0, 0.0, -1.0]])), Ordering::Less);
+/// assert_eq!(orient3d(a, b, c, Vec3::new([[3.0, 2.0, 0.0]])), Ordering::Equal);
+/// ```
+#[inline]
+pub fn orient3d<T: Orient3d>(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>, d: Vec3<T>) -> Ordering {
+    T::orient3d(a, b, c, d)
+}
+
+/// Compute the barycentric coordinates of `p` with respect to the triangle `a`, `b`, `c`.
+///
+/// The result `(u, v, w)` satisfies `a * u + b * v + c * w == p` and `u + v + w == 1`.
+/// Returns `None` if the triangle has zero area.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::try_barycentric};
+///
+/// let a = Vec2::new([[0.0, 0.0]]);
+/// let b = Vec2::new([[1.0, 0.0]]);
+/// let c = Vec2::new([[0.0, 1.0]]);
+///
+/// let uvw = try_barycentric(Vec2::new([[0.25, 0.5]]), a, b, c).unwrap();
+/// assert_eq!(uvw, mats::Vec3::new([[0.25, 0.25, 0.5]]));
+///
+/// assert_eq!(try_barycentric(a, a, b, b * 2.0), None);
+/// ```
+#[inline]
+pub fn try_barycentric<T: Float>(
+    p: Vec2<T>,
+    a: Vec2<T>,
+    b: Vec2<T>,
+    c: Vec2<T>,
+) -> Option<Vec3<T>> {
+    let (v0, v1, v2) = (b - a, c - a, p - a);
+    let denom = v0.x() * v1.y() - v1.x() * v0.y();
+    if denom == T::zero() {
+        return None;
+    }
+    let v = (v2.x() * v1.y() - v1.x() * v2.y()) / denom;
+    let w = (v0.x() * v2.y() - v2.x() * v0.y()) / denom;
+    Some(Vec3::new([[T::unit_one() - v - w, v, w]]))
+}
+
+/// Compute the barycentric coordinates of `p` with respect to the triangle `a`, `b`, `c`.
+///
+/// The result `(u, v, w)` satisfies `a * u + b * v + c * w == p` and `u + v + w == 1`.
+/// For a triangle with zero area the result is not finite; use [`try_barycentric`]
+/// to detect that case.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, Vec3, graphics::barycentric};
+///
+/// let a = Vec2::new([[0.0, 0.0]]);
+/// let b = Vec2::new([[1.0, 0.0]]);
+/// let c = Vec2::new([[0.0, 1.0]]);
+///
+/// assert_eq!(barycentric(b, a, b, c), Vec3::new([[0.0, 1.0, 0.0]]));
+/// ```
+#[inline]
+pub fn barycentric<T: Float>(p: Vec2<T>, a: Vec2<T>, b: Vec2<T>, c: Vec2<T>) -> Vec3<T> {
+    let (v0, v1, v2) = (b - a, c - a, p - a);
+    let denom = v0.x() * v1.y() - v1.x() * v0.y();
+    let v = (v2.x() * v1.y() - v1.x() * v2.y()) / denom;
+    let w = (v0.x() * v2.y() - v2.x() * v0.y()) / denom;
+    Vec3::new([[T::unit_one() - v - w, v, w]])
+}
+
+/// Compute the barycentric coordinates of the projection of `p` onto the plane
+/// of the 3D triangle `a`, `b`, `c`.
+///
+/// Returns `None` if the triangle has zero area.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::try_barycentric3d};
+///
+/// let a = Vec3::new([[0.0, 0.0, 0.0]]);
+/// let b = Vec3::new([[2.0, 0.0, 0.0]]);
+/// let c = Vec3::new([[0.0, 2.0, 0.0]]);
+///
+/// // A point above the triangle projects straight down onto it.
+/// let uvw = try_barycentric3d(Vec3::new([[1.0, 0.5, 3.0]]), a, b, c).unwrap();
+/// assert_eq!(uvw, Vec3::new([[0.25, 0.5, 0.25]]));
+/// ```
+#[inline]
+pub fn try_barycentric3d<T: Float>(
+    p: Vec3<T>,
+    a: Vec3<T>,
+    b: Vec3<T>,
+    c: Vec3<T>,
+) -> Option<Vec3<T>> {
+    let (v0, v1, v2) = (b - a, c - a, p - a);
+    let d00 = v0.inner_product(&v0);
+    let d01 = v0.inner_product(&v1);
+    let d11 = v1.inner_product(&v1);
+    let d20 = v2.inner_product(&v0);
+    let d21 = v2.inner_product(&v1);
+    let denom = d00 * d11 - d01 * d01;
+    if denom == T::zero() {
+        return None;
+    }
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    Some(Vec3::new([[T::unit_one() - v - w, v, w]]))
+}
+
+/// Test whether `p` lies inside the triangle `a`, `b`, `c`, including its edges.
+///
+/// A triangle with zero area contains no points.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::point_in_triangle};
+///
+/// let a = Vec2::new([[0.0, 0.0]]);
+/// let b = Vec2::new([[1.0, 0.0]]);
+/// let c = Vec2::new([[0.0, 1.0]]);
+///
+/// assert!(point_in_triangle(Vec2::new([[0.25, 0.25]]), a, b, c));
+/// assert!(point_in_triangle(Vec2::new([[0.5, 0.0]]), a, b, c));
+/// assert!(!point_in_triangle(Vec2::new([[1.0, 1.0]]), a, b, c));
+/// ```
+#[inline]
+pub fn point_in_triangle<T: Float>(p: Vec2<T>, a: Vec2<T>, b: Vec2<T>, c: Vec2<T>) -> bool {
+    match try_barycentric(p, a, b, c) {
+        Some(uvw) => uvw.x() >= T::zero() && uvw.y() >= T::zero() && uvw.z() >= T::zero(),
+        None => false,
+    }
+}
+
+/// A ray starting at `origin` and extending along `direction`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray<T> {
+    /// The starting point of the ray.
+    pub origin: Vec3<T>,
+    /// The direction of the ray. It is not required to be normalized.
+    pub direction: Vec3<T>,
+}
+
+impl<T: Float> Ray<T> {
+    /// Create a new ray.
+    #[inline]
+    pub fn new(origin: Vec3<T>, direction: Vec3<T>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Return the point `origin + direction * t`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, graphics::Ray};
+    ///
+    /// let ray = Ray::new(Vec3::new([[1.0, 0.0, 0.0]]), Vec3::new([[0.0, 2.0, 0.0]]));
+    ///
+    /// assert_eq!(ray.at(1.5), Vec3::new([[1.0, 3.0, 0.0]]));
+    /// ```
+    #[inline]
+    pub fn at(&self, t: T) -> Vec3<T> {
+        self.origin + self.direction * t
+    }
+}
+
+/// A plane of the points `p` satisfying `normal · p + d == 0`.
+///
+/// The constructors keep `normal` at unit length, so `signed_distance` is a true
+/// distance, positive on the side the normal points to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane<T> {
+    /// The unit normal of the plane.
+    pub normal: Vec3<T>,
+    /// The offset of the plane, `-normal · p` for any point `p` on it.
+    pub d: T,
+}
+
+impl<T: Float> Plane<T> {
+    /// Create a plane through `point` with the given `normal`.
+    ///
+    /// The normal is normalized; a zero normal gives a non-finite plane.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, graphics::Plane};
+    ///
+    /// let plane = Plane::from_point_normal(Vec3::new([[0.0, 2.0, 0.0]]), Vec3::new([[0.0, 3.0, 0.0]]));
+    ///
+    /// assert_eq!(plane.normal, Vec3::new([[0.0, 1.0, 0.0]]));
+    /// assert_eq!(plane.d, -2.0);
+    /// ```
+    #[inline]
+    pub fn from_point_normal(point: Vec3<T>, normal: Vec3<T>) -> Self {
+        let normal = normal / normal.inner_product(&normal).sqrt();
+        Self {
+            normal,
+            d: -normal.inner_product(&point),
+        }
+    }
+
+    /// Create the plane through the points `a`, `b` and `c`.
+    ///
+    /// The normal points to the side from which `a`, `b`, `c` appear counterclockwise.
+    /// Collinear points give a non-finite plane.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, graphics::Plane};
+    ///
+    /// let plane = Plane::from_points(
+    ///     Vec3::new([[0.0, 0.0, 1.0]]),
+    ///     Vec3::new([[1.0, 0.0, 1.0]]),
+    ///     Vec3::new([[0.0, 1.0, 1.0]]),
+    /// );
+    ///
+    /// assert_eq!(plane.normal, Vec3::new([[0.0, 0.0, 1.0]]));
+    /// assert_eq!(plane.d, -1.0);
+    /// ```
+    #[inline]
+    pub fn from_points(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Self {
+        Self::from_point_normal(a, (b - a).cross(&(c - a)))
+    }
+
+    /// Return the signed distance from the plane to `p`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, graphics::Plane};
+    ///
+    /// let plane = Plane::from_point_normal(Vec3::new([[0.0, 0.0, 1.0]]), Vec3::new([[0.0, 0.0, 1.0]]));
+    ///
+    /// assert_eq!(plane.signed_distance(Vec3::new([[5.0, 5.0, 3.0]])), 2.0);
+    /// assert_eq!(plane.signed_distance(Vec3::new([[5.0, 5.0, -3.0]])), -4.0);
+    /// ```
+    #[inline]
+    pub fn signed_distance(&self, p: Vec3<T>) -> T {
+        self.normal.inner_product(&p) + self.d
+    }
+
+    /// Return the orthogonal projection of `p` onto the plane.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, graphics::Plane};
+    ///
+    /// let plane = Plane::from_point_normal(Vec3::new([[0.0, 0.0, 1.0]]), Vec3::new([[0.0, 0.0, 1.0]]));
+    ///
+    /// assert_eq!(plane.project_point(Vec3::new([[5.0, 5.0, 3.0]])), Vec3::new([[5.0, 5.0, 1.0]]));
+    /// ```
+    #[inline]
+    pub fn project_point(&self, p: Vec3<T>) -> Vec3<T> {
+        p - self.normal * self.signed_distance(p)
+    }
+
+    /// Intersect the plane with `ray`.
+    ///
+    /// Returns the parameter `t` of the intersection point `ray.at(t)`, or `None` if
+    /// the ray is parallel to the plane or the plane is behind the ray's origin.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, graphics::{Plane, Ray}};
+    ///
+    /// let plane = Plane::from_point_normal(Vec3::new([[0.0, 0.0, 1.0]]), Vec3::new([[0.0, 0.0, 1.0]]));
+    /// let ray = Ray::new(Vec3::new([[1.0, 1.0, 5.0]]), Vec3::new([[0.0, 0.0, -2.0]]));
+    ///
+    /// assert_eq!(plane.intersect_ray(&ray), Some(2.0));
+    /// assert_eq!(ray.at(2.0), Vec3::new([[1.0, 1.0, 1.0]]));
+    /// ```
+    #[inline]
+    pub fn intersect_ray(&self, ray: &Ray<T>) -> Option<T> {
+        let denom = self.normal.inner_product(&ray.direction);
+        if denom == T::zero() {
+            return None;
+        }
+        let t = -self.signed_distance(ray.origin) / denom;
+        if t >= T::zero() { Some(t) } else { None }
+    }
+
+    /// Intersect the plane with `other`.
+    ///
+    /// Returns the line of intersection as a ray with direction
+    /// `self.normal × other.normal`, or `None` if the planes are parallel.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, graphics::Plane};
+    ///
+    /// let zero = Vec3::new([[0.0, 0.0, 0.0]]);
+    /// let a = Plane::from_point_normal(zero, Vec3::new([[1.0, 0.0, 0.0]]));
+    /// let b = Plane::from_point_normal(zero, Vec3::new([[0.0, 1.0, 0.0]]));
+    ///
+    /// let line = a.intersect_plane(&b).unwrap();
+    /// assert_eq!(line.origin, zero);
+    /// assert_eq!(line.direction, Vec3::new([[0.0, 0.0, 1.0]]));
+    /// ```
+    #[inline]
+    pub fn intersect_plane(&self, other: &Plane<T>) -> Option<Ray<T>> {
+        let direction = self.normal.cross(&other.normal);
+        let len2 = direction.inner_product(&direction);
+        if len2 <= T::EPSILON * T::EPSILON {
+            return None;
+        }
+        let origin = (direction.cross(&other.normal) * self.d
+            + self.normal.cross(&direction) * other.d)
+            / len2;
+        Some(Ray::new(origin, direction))
+    }
+
+    /// Return the plane transformed by the affine transformation `m`.
+    ///
+    /// Normals transform by the inverse transpose of the linear part of `m`; this
+    /// uses its cofactor matrix, which has the same direction, so non-uniform
+    /// scaling is handled without inverting `m`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, graphics::{Plane, translate3d}};
+    ///
+    /// let plane = Plane::from_point_normal(Vec3::new([[0.0, 0.0, 0.0]]), Vec3::new([[0.0, 0.0, 1.0]]));
+    /// let moved = plane.transformed(&translate3d(Vec3::new([[0.0, 0.0, 2.0]])));
+    ///
+    /// assert_eq!(moved.normal, plane.normal);
+    /// assert_eq!(moved.d, -2.0);
+    /// ```
+    pub fn transformed(&self, m: &Mat4<T>) -> Self {
+        let c0 = Vec3::new([[m[(0, 0)], m[(1, 0)], m[(2, 0)]]]);
+        let c1 = Vec3::new([[m[(0, 1)], m[(1, 1)], m[(2, 1)]]]);
+        let c2 = Vec3::new([[m[(0, 2)], m[(1, 2)], m[(2, 2)]]]);
+        let (k0, k1, k2) = (c1.cross(&c2), c2.cross(&c0), c0.cross(&c1));
+        let mut normal = k0 * self.normal.x() + k1 * self.normal.y() + k2 * self.normal.z();
+        if c0.inner_product(&k0) < T::zero() {
+            normal = -normal;
+        }
+
+        let point = self.normal * -self.d;
+        let point = (*m * Vec4::from((point, T::unit_one()))).xyz();
+        Self::from_point_normal(point, normal)
+    }
+}
+
+/// An axis-aligned bounding box spanning `min..=max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb<T> {
+    /// The corner with the smallest coordinates.
+    pub min: Vec3<T>,
+    /// The corner with the largest coordinates.
+    pub max: Vec3<T>,
+}
+
+impl<T: Float> Aabb<T> {
+    /// Create a box from its minimum and maximum corners.
+    #[inline]
+    pub fn new(min: Vec3<T>, max: Vec3<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+/// How a volume lies relative to a [`Frustum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// Entirely inside the frustum.
+    Inside,
+    /// Crossing at least one of the frustum planes.
+    Intersecting,
+    /// Entirely outside the frustum.
+    Outside,
+}
+
+/// The view frustum of a camera as six planes with normals pointing inwards.
+///
+/// The tests are conservative: a volume near a frustum corner may be reported as
+/// `Intersecting` although it is outside, but never the other way round.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::{Containment, Frustum, perspective}};
+///
+/// let frustum = Frustum::from_view_proj(&perspective(1.5, 1.0, 0.1, 100.0));
+///
+/// assert_eq!(frustum.test_sphere(Vec3::new([[0.0, 0.0, -10.0]]), 1.0), Containment::Inside);
+/// assert_eq!(frustum.test_sphere(Vec3::new([[0.0, 0.0, 10.0]]), 1.0), Containment::Outside);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum<T> {
+    /// The left, right, bottom, top, near and far planes.
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T: Float> Frustum<T> {
+    /// Extract the frustum from a `proj * view` matrix mapping world space to the
+    /// `[-1, 1]` clip cube, as built by [`perspective`] and [`orthographic`].
+    ///
+    /// This is the Gribb–Hartmann method: each plane is the sum or difference of the
+    /// last row of the matrix and one of the others.
+    #[inline]
+    pub fn from_view_proj(m: &Mat4<T>) -> Self {
+        let [r0, r1, r2, r3] = m.to_rows_array_2d();
+        let plane = |sign: T, r: [T; 4]| {
+            let [a, b, c, d] = core::array::from_fn(|i| r3[i] + sign * r[i]);
+            let normal = Vec3::new([[a, b, c]]);
+            let length = normal.inner_product(&normal).sqrt();
+            Plane {
+                normal: normal / length,
+                d: d / length,
+            }
+        };
+        let i = T::unit_one();
+        Self {
+            planes: [
+                plane(i, r0),
+                plane(-i, r0),
+                plane(i, r1),
+                plane(-i, r1),
+                plane(i, r2),
+                plane(-i, r2),
+            ],
+        }
+    }
+
+    /// Classify the sphere at `center` with `radius` against the frustum.
+    #[inline]
+    pub fn test_sphere(&self, center: Vec3<T>, radius: T) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.signed_distance(center);
+            if distance < -radius {
+                return Containment::Outside;
+            }
+            if distance < radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Classify an axis-aligned box against the frustum.
+    ///
+    /// Only two corners are tested per plane: the one furthest along the normal
+    /// (the p-vertex), which decides `Outside`, and the opposite one (the n-vertex),
+    /// which decides `Intersecting`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, graphics::{Aabb, Containment, Frustum, perspective}};
+    ///
+    /// let frustum = Frustum::from_view_proj(&perspective(1.5, 1.0, 1.0, 100.0));
+    /// let near = Aabb::new(Vec3::new([[-0.5, -0.5, -2.0]]), Vec3::new([[0.5, 0.5, 0.0]]));
+    ///
+    /// assert_eq!(frustum.test_aabb(&near), Containment::Intersecting);
+    /// ```
+    #[inline]
+    pub fn test_aabb(&self, aabb: &Aabb<T>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let n = plane.normal;
+            let pick = |i: usize, towards: bool| {
+                if (n[i] >= T::zero()) == towards {
+                    aabb.max[i]
+                } else {
+                    aabb.min[i]
+                }
+            };
+            let p_vertex = Vec3::new([[pick(0, true), pick(1, true), pick(2, true)]]);
+            if plane.signed_distance(p_vertex) < T::zero() {
+                return Containment::Outside;
+            }
+            let n_vertex = Vec3::new([[pick(0, false), pick(1, false), pick(2, false)]]);
+            if plane.signed_distance(n_vertex) < T::zero() {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+}
+
+/// Create the matrix projecting geometry onto a plane along rays from a light, for
+/// planar shadows.
+///
+/// `plane` holds the coefficients `(a, b, c, d)` of `ax + by + cz + d == 0`. `light`
+/// is a position with `w == 1` or a direction towards the light with `w == 0`. The
+/// result is `(plane · light) I - light planeᵀ`; divide the projected points by
+/// their `w`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec4, graphics::shadow_matrix};
+///
+/// // The ground plane y == 0, lit from straight above.
+/// let ground = Vec4::new([[0.0, 1.0, 0.0, 0.0]]);
+/// let sun = Vec4::new([[0.0, 1.0, 0.0, 0.0]]);
+/// let m = shadow_matrix(ground, sun);
+///
+/// assert_eq!(m * Vec4::new([[2.0, 5.0, 3.0, 1.0]]), Vec4::new([[2.0, 0.0, 3.0, 1.0]]));
+/// ```
+#[inline]
+pub fn shadow_matrix<T: Float>(plane: Vec4<T>, light: Vec4<T>) -> Mat4<T> {
+    Mat4::I() * plane.inner_product(&light) - Mat4::from_outer_product(&light, &plane)
+}
+
+/// Create the coefficients `(a, b, c, d)` of the plane through `a`, `b` and `c`, in
+/// the `Vec4` form used by [`shadow_matrix`].
+///
+/// The normal `(a, b, c)` has unit length and faces the side from which the points
+/// appear counterclockwise, like [`Plane::from_points`]. Collinear points give a
+/// non-finite plane.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, Vec4, graphics::plane_from_points};
+///
+/// let plane = plane_from_points(
+///     Vec3::new([[0.0, 2.0, 0.0]]),
+///     Vec3::new([[0.0, 2.0, 1.0]]),
+///     Vec3::new([[1.0, 2.0, 0.0]]),
+/// );
+///
+/// assert_eq!(plane, Vec4::new([[0.0, 1.0, 0.0, -2.0]]));
+/// ```
+#[inline]
+pub fn plane_from_points<T: Float>(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Vec4<T> {
+    let plane = Plane::from_points(a, b, c);
+    Vec4::from((plane.normal, plane.d))
+}
+
+impl<T: Float> Vec4<T> {
+    /// Scale the plane coefficients `(a, b, c, d)` of `ax + by + cz + d == 0` so the
+    /// normal `(a, b, c)` has unit length.
+    ///
+    /// The plane keeps the same points and the same front side.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec4;
+    ///
+    /// let plane = Vec4::new([[0.0, 3.0, 4.0, -10.0]]);
+    ///
+    /// assert_eq!(plane.normalize_plane(), Vec4::new([[0.0, 0.6, 0.8, -2.0]]));
+    /// ```
+    #[inline]
+    pub fn normalize_plane(self) -> Self {
+        let normal = self.xyz();
+        self / normal.inner_product(&normal).sqrt()
+    }
+
+    /// Return the signed distance from the plane with coefficients `(a, b, c, d)` to
+    /// `p`, positive on the side the normal `(a, b, c)` points to.
+    ///
+    /// The plane does not need to be normalized.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec3, Vec4};
+    ///
+    /// // The plane y == 1, facing up.
+    /// let plane = Vec4::new([[0.0, 2.0, 0.0, -2.0]]);
+    ///
+    /// assert_eq!(plane.plane_distance(Vec3::new([[5.0, 4.0, 0.0]])), 3.0);
+    /// assert_eq!(plane.plane_distance(Vec3::new([[5.0, 0.0, 0.0]])), -1.0);
+    /// ```
+    #[inline]
+    pub fn plane_distance(&self, p: Vec3<T>) -> T {
+        let normal = self.xyz();
+        (normal.inner_product(&p) + self.w()) / normal.inner_product(&normal).sqrt()
+    }
+}
+
+/// Evaluate the quadratic Bézier curve with control points `p0`, `p1`, `p2` at `t`.
+///
+/// The weights are scalars, so this works for any matrix type, not only vectors.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::quadratic_bezier};
+///
+/// let p0 = Vec2::new([[0.0, 0.0]]);
+/// let p1 = Vec2::new([[1.0, 2.0]]);
+/// let p2 = Vec2::new([[2.0, 0.0]]);
+///
+/// assert_eq!(quadratic_bezier(p0, p1, p2, 0.5), Vec2::new([[1.0, 1.0]]));
+/// ```
+#[inline]
+pub fn quadratic_bezier<T: Float, const R: usize, const C: usize>(
+    p0: Mat<R, C, T>,
+    p1: Mat<R, C, T>,
+    p2: Mat<R, C, T>,
+    t: T,
+) -> Mat<R, C, T> {
+    let u = T::unit_one() - t;
+    let two = T::unit_one() + T::unit_one();
+    p0 * (u * u) + p1 * (two * u * t) + p2 * (t * t)
+}
+
+/// Evaluate the derivative of [`quadratic_bezier`] at `t`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::quadratic_bezier_derivative};
+///
+/// let p0 = Vec2::new([[0.0, 0.0]]);
+/// let p1 = Vec2::new([[1.0, 2.0]]);
+/// let p2 = Vec2::new([[2.0, 0.0]]);
+///
+/// assert_eq!(quadratic_bezier_derivative(p0, p1, p2, 0.5), Vec2::new([[2.0, 0.0]]));
+/// ```
+#[inline]
+pub fn quadratic_bezier_derivative<T: Float, const R: usize, const C: usize>(
+    p0: Mat<R, C, T>,
+    p1: Mat<R, C, T>,
+    p2: Mat<R, C, T>,
+    t: T,
+) -> Mat<R, C, T> {
+    let u = T::unit_one() - t;
+    let two = T::unit_one() + T::unit_one();
+    (p1 - p0) * (two * u) + (p2 - p1) * (two * t)
+}
+
+/// Evaluate the cubic Bézier curve with control points `p0`, `p1`, `p2`, `p3` at `t`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::cubic_bezier};
+///
+/// let p0 = Vec2::new([[0.0, 0.0]]);
+/// let p1 = Vec2::new([[0.0, 1.0]]);
+/// let p2 = Vec2::new([[1.0, 1.0]]);
+/// let p3 = Vec2::new([[1.0, 0.0]]);
+///
+/// assert_eq!(cubic_bezier(p0, p1, p2, p3, 0.0), p0);
+/// assert_eq!(cubic_bezier(p0, p1, p2, p3, 0.5), Vec2::new([[0.5, 0.75]]));
+/// assert_eq!(cubic_bezier(p0, p1, p2, p3, 1.0), p3);
+/// ```
+#[inline]
+pub fn cubic_bezier<T: Float, const R: usize, const C: usize>(
+    p0: Mat<R, C, T>,
+    p1: Mat<R, C, T>,
+    p2: Mat<R, C, T>,
+    p3: Mat<R, C, T>,
+    t: T,
+) -> Mat<R, C, T> {
+    let u = T::unit_one() - t;
+    let three = T::unit_one() + T::unit_one() + T::unit_one();
+    p0 * (u * u * u) + p1 * (three * u * u * t) + p2 * (three * u * t * t) + p3 * (t * t * t)
+}
+
+/// Evaluate the derivative of [`cubic_bezier`] at `t`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::cubic_bezier_derivative};
+///
+/// let p0 = Vec2::new([[0.0, 0.0]]);
+/// let p1 = Vec2::new([[0.0, 1.0]]);
+/// let p2 = Vec2::new([[1.0, 1.0]]);
+/// let p3 = Vec2::new([[1.0, 0.0]]);
+///
+/// assert_eq!(cubic_bezier_derivative(p0, p1, p2, p3, 0.0), Vec2::new([[0.0, 3.0]]));
+/// assert_eq!(cubic_bezier_derivative(p0, p1, p2, p3, 0.5), Vec2::new([[1.5, 0.0]]));
+/// ```
+#[inline]
+pub fn cubic_bezier_derivative<T: Float, const R: usize, const C: usize>(
+    p0: Mat<R, C, T>,
+    p1: Mat<R, C, T>,
+    p2: Mat<R, C, T>,
+    p3: Mat<R, C, T>,
+    t: T,
+) -> Mat<R, C, T> {
+    let u = T::unit_one() - t;
+    let three = T::unit_one() + T::unit_one() + T::unit_one();
+    let six = three + three;
+    (p1 - p0) * (three * u * u) + (p2 - p1) * (six * u * t) + (p3 - p2) * (three * t * t)
+}
+
+/// Evaluate the cubic Hermite curve from `p0` with tangent `m0` to `p1` with
+/// tangent `m1` at `t`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::hermite};
+///
+/// let p0 = Vec2::new([[0.0, 0.0]]);
+/// let m0 = Vec2::new([[1.0, 0.0]]);
+/// let p1 = Vec2::new([[1.0, 1.0]]);
+/// let m1 = Vec2::new([[0.0, 1.0]]);
+///
+/// assert_eq!(hermite(p0, m0, p1, m1, 0.0), p0);
+/// assert_eq!(hermite(p0, m0, p1, m1, 1.0), p1);
+/// ```
+#[inline]
+pub fn hermite<T: Float, const R: usize, const C: usize>(
+    p0: Mat<R, C, T>,
+    m0: Mat<R, C, T>,
+    p1: Mat<R, C, T>,
+    m1: Mat<R, C, T>,
+    t: T,
+) -> Mat<R, C, T> {
+    let one = T::unit_one();
+    let two = one + one;
+    let three = two + one;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    p0 * (two * t3 - three * t2 + one)
+        + m0 * (t3 - two * t2 + t)
+        + p1 * (three * t2 - two * t3)
+        + m1 * (t3 - t2)
+}
+
+/// Evaluate the derivative of [`hermite`] at `t`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::hermite_derivative};
+///
+/// let p0 = Vec2::new([[0.0, 0.0]]);
+/// let m0 = Vec2::new([[1.0, 0.0]]);
+/// let p1 = Vec2::new([[1.0, 1.0]]);
+/// let m1 = Vec2::new([[0.0, 1.0]]);
+///
+/// assert_eq!(hermite_derivative(p0, m0, p1, m1, 0.0), m0);
+/// assert_eq!(hermite_derivative(p0, m0, p1, m1, 1.0), m1);
+/// ```
+#[inline]
+pub fn hermite_derivative<T: Float, const R: usize, const C: usize>(
+    p0: Mat<R, C, T>,
+    m0: Mat<R, C, T>,
+    p1: Mat<R, C, T>,
+    m1: Mat<R, C, T>,
+    t: T,
+) -> Mat<R, C, T> {
+    let one = T::unit_one();
+    let two = one + one;
+    let three = two + one;
+    let six = three + three;
+    let t2 = t * t;
+    (p1 - p0) * (six * t - six * t2)
+        + m0 * (three * t2 - (two + two) * t + one)
+        + m1 * (three * t2 - two * t)
+}
+
+/// Evaluate the uniform Catmull-Rom spline segment between `p1` and `p2` at `t`.
+///
+/// `p0` and `p3` are the neighbouring points that set the tangents, so the curve
+/// passes through `p1` at `t = 0` and through `p2` at `t = 1`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::catmull_rom};
+///
+/// let p0 = Vec2::new([[0.0, 0.0]]);
+/// let p1 = Vec2::new([[1.0, 1.0]]);
+/// let p2 = Vec2::new([[2.0, 1.0]]);
+/// let p3 = Vec2::new([[3.0, 0.0]]);
+///
+/// assert_eq!(catmull_rom(p0, p1, p2, p3, 0.0), p1);
+/// assert_eq!(catmull_rom(p0, p1, p2, p3, 1.0), p2);
+/// ```
+#[inline]
+pub fn catmull_rom<T: Float, const R: usize, const C: usize>(
+    p0: Mat<R, C, T>,
+    p1: Mat<R, C, T>,
+    p2: Mat<R, C, T>,
+    p3: Mat<R, C, T>,
+    t: T,
+) -> Mat<R, C, T> {
+    let two = T::unit_one() + T::unit_one();
+    hermite(p1, (p2 - p0) / two, p2, (p3 - p1) / two, t)
+}
+
+/// Evaluate the derivative of [`catmull_rom`] at `t`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, graphics::catmull_rom_derivative};
+///
+/// let p0 = Vec2::new([[0.0, 0.0]]);
+/// let p1 = Vec2::new([[1.0, 1.0]]);
+/// let p2 = Vec2::new([[2.0, 1.0]]);
+/// let p3 = Vec2::new([[3.0, 0.0]]);
+///
+/// assert_eq!(catmull_rom_derivative(p0, p1, p2, p3, 0.0), Vec2::new([[1.0, 0.5]]));
+/// ```
+#[inline]
+pub fn catmull_rom_derivative<T: Float, const R: usize, const C: usize>(
+    p0: Mat<R, C, T>,
+    p1: Mat<R, C, T>,
+    p2: Mat<R, C, T>,
+    p3: Mat<R, C, T>,
+    t: T,
+) -> Mat<R, C, T> {
+    let two = T::unit_one() + T::unit_one();
+    hermite_derivative(p1, (p2 - p0) / two, p2, (p3 - p1) / two, t)
+}
+
+/// Returns the rotation matrix of the rotation vector `w`, the exponential map
+/// from so(3) to SO(3).
+///
+/// `w` is the rotation axis scaled by the angle in radians. Small angles use a
+/// Taylor expansion instead of dividing by the angle, so `w = 0` gives the identity.
+///
+/// # Example
+/// ```
+/// use mats::{Mat3, Vec3, graphics::exp_so3};
+///
+/// let r = exp_so3(Vec3::new([[0.0, 0.0, std::f64::consts::FRAC_PI_2]]));
+/// let v = r * Vec3::new([[1.0, 0.0, 0.0]]);
+///
+/// assert!(v.x().abs() < 1e-15 && (v.y() - 1.0).abs() < 1e-15);
+/// assert_eq!(exp_so3(Vec3::new([[0.0, 0.0, 0.0]])), Mat3::I());
+/// ```
+pub fn exp_so3<T: Float>(w: Vec3<T>) -> Mat3<T> {
+    let (a, b, _) = so3_coefficients(w);
+    let k = w.cross_matrix();
+    Mat3::I() + k * a + k * k * b
+}
+
+/// Returns the rotation vector of the rotation matrix `r`, the logarithmic map
+/// from SO(3) to so(3) and the inverse of [`exp_so3`].
+///
+/// The angle of the result is in `[0, π]`. Rotations by about `π` take the axis from
+/// the symmetric part of `r`, where the antisymmetric part vanishes.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::{exp_so3, log_so3}};
+///
+/// let w = Vec3::<f64>::new([[0.3, -0.2, 0.9]]);
+/// let error = log_so3(exp_so3(w)) - w;
+///
+/// assert!(error.x().abs() < 1e-15 && error.y().abs() < 1e-15 && error.z().abs() < 1e-15);
+/// ```
+pub fn log_so3<T: Float>(r: Mat3<T>) -> Vec3<T> {
+    let one = T::unit_one();
+    let two = one + one;
+    // `v` is `sin(angle) * axis`.
+    let v = Vec3::new([[
+        r[(2, 1)] - r[(1, 2)],
+        r[(0, 2)] - r[(2, 0)],
+        r[(1, 0)] - r[(0, 1)],
+    ]]) / two;
+    let sin = v.inner_product(&v).sqrt();
+    let cos = (r[(0, 0)] + r[(1, 1)] + r[(2, 2)] - one) / two;
+    let angle = sin.atan2(cos);
+
+    if cos >= T::zero() {
+        // angle / sin(angle), with its Taylor expansion for tiny angles.
+        let six = two + two + two;
+        let scale = if sin > T::EPSILON {
+            angle / sin
+        } else {
+            one + angle * angle / six
+        };
+        return v * scale;
+    }
+
+    // The symmetric part is `cos I + (1 - cos) axis axisᵀ`.
+    let s = |i: usize, j: usize| (r[(i, j)] + r[(j, i)]) / two;
+    let k = (0..3)
+        .max_by(|&i, &j| s(i, i).partial_cmp(&s(j, j)).unwrap_or(Ordering::Equal))
+        .unwrap_or(0);
+    let c = one - cos;
+    let ak = ((s(k, k) - cos) / c).sqrt();
+    let mut axis = Vec3::new([[s(0, k) / c, s(1, k) / c, s(2, k) / c]]) / ak;
+    axis[k] = ak;
+    if axis.inner_product(&v) < T::zero() {
+        axis = -axis;
+    }
+    axis * angle
+}
+
+/// Returns the rigid transformation of the twist `(w, v)`, the exponential map from
+/// se(3) to SE(3).
+///
+/// `w` is the rotation vector as in [`exp_so3`] and `v` the translational part.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, Vec4, graphics::exp_se3};
+///
+/// // Without rotation, the twist is a plain translation.
+/// let m = exp_se3(Vec3::new([[0.0, 0.0, 0.0]]), Vec3::new([[1.0, 2.0, 3.0]]));
+///
+/// assert_eq!(m * Vec4::new([[0.0, 0.0, 0.0, 1.0]]), Vec4::new([[1.0, 2.0, 3.0, 1.0]]));
+/// ```
+pub fn exp_se3<T: Float>(w: Vec3<T>, v: Vec3<T>) -> Mat4<T> {
+    let (a, b, c) = so3_coefficients(w);
+    let k = w.cross_matrix();
+    let k2 = k * k;
+    let r = Mat3::I() + k * a + k2 * b;
+    let t = (Mat3::I() + k * b + k2 * c) * v;
+
+    let mut m = homogeneous3(r);
+    m.data[3] = [t.x(), t.y(), t.z(), T::unit_one()];
+    m
+}
+
+/// Returns the twist `(w, v)` of the rigid transformation `m`, the logarithmic map
+/// from SE(3) to se(3) and the inverse of [`exp_se3`].
+///
+/// Only the rotation and translation parts of `m` are read.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::{exp_se3, log_se3}};
+///
+/// let (w, v) = (Vec3::<f64>::new([[0.1, 0.5, -0.3]]), Vec3::new([[1.0, -2.0, 0.5]]));
+/// let (w2, v2) = log_se3(exp_se3(w, v));
+///
+/// assert!((w2 - w).raw_data()[0].iter().all(|e: &f64| e.abs() < 1e-12));
+/// assert!((v2 - v).raw_data()[0].iter().all(|e: &f64| e.abs() < 1e-12));
+/// ```
+pub fn log_se3<T: Float>(m: Mat4<T>) -> (Vec3<T>, Vec3<T>) {
+    let r = Mat3::new(core::array::from_fn(|j| {
+        core::array::from_fn(|i| m.data[j][i])
+    }));
+    let t = Vec3::new([[m.data[3][0], m.data[3][1], m.data[3][2]]]);
+    let w = log_so3(r);
+
+    let one = T::unit_one();
+    let two = one + one;
+    let theta2 = w.inner_product(&w);
+    let (a, b, _) = so3_coefficients(w);
+    // The coefficient of `[w]×²` in the inverse of the left Jacobian.
+    let d = if theta2.sqrt() > T::EPSILON.sqrt() {
+        (one - a / (two * b)) / theta2
+    } else {
+        let twelve = (two + two) * (two + one);
+        one / twelve + theta2 / (twelve * (two + two + one) * (two + two + two + two + two + two))
+    };
+    let k = w.cross_matrix();
+    let v = (Mat3::I() - k / two + k * k * d) * t;
+    (w, v)
+}
+
+/// Interpolate between the rigid transforms `a` and `b`, rotating along the
+/// shortest arc at constant angular speed and moving the translation linearly.
+///
+/// Lerping the matrices themselves shrinks the rotation part in between; here the
+/// relative rotation is taken through [`log_so3`] and scaled by `t` in [`exp_so3`],
+/// so every intermediate transform stays rigid. `t == 0` and `t == 1` return `a`
+/// and `b` exactly. Both inputs must be rotations followed by translations.
+///
+/// # Example
+/// ```
+/// use mats::{Mat4, Vec3, Vec4, graphics::{interpolate_rigid, rotate3d_z, translate3d}};
+///
+/// let a = Mat4::I();
+/// let b = translate3d(Vec3::new([[4.0, 0.0, 0.0]])) * rotate3d_z(std::f64::consts::PI / 2.0);
+/// let mid = interpolate_rigid(&a, &b, 0.5);
+///
+/// // Half way along, and turned by 45°.
+/// let p = mid * Vec4::new([[1.0, 0.0, 0.0, 1.0]]);
+/// assert!((p.x() - (2.0 + 0.5f64.sqrt())).abs() < 1e-12);
+/// assert!((p.y() - 0.5f64.sqrt()).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn interpolate_rigid<T: Float>(a: &Mat4<T>, b: &Mat4<T>, t: T) -> Mat4<T> {
+    if t == T::zero() {
+        return *a;
+    }
+    if t == T::unit_one() {
+        return *b;
+    }
+    let linear = |m: &Mat4<T>| -> Mat3<T> {
+        Mat::new(core::array::from_fn(|j| {
+            core::array::from_fn(|i| m.data[j][i])
+        }))
+    };
+    let ra = linear(a);
+    let w = log_so3(ra.T() * linear(b));
+
+    let mut m = homogeneous3(ra * exp_so3(w * t));
+    let (ta, tb) = (a.data[3], b.data[3]);
+    m.data[3] = core::array::from_fn(|i| ta[i] + (tb[i] - ta[i]) * t);
+    m
+}
+
+/// The coefficients `sin θ / θ`, `(1 - cos θ) / θ²` and `(θ - sin θ) / θ³` of the
+/// exponential maps, with `θ = |w|`, using Taylor expansions for small `θ`.
+fn so3_coefficients<T: Float>(w: Vec3<T>) -> (T, T, T) {
+    let one = T::unit_one();
+    let two = one + one;
+    let six = two + two + two;
+    let theta2 = w.inner_product(&w);
+    let theta = theta2.sqrt();
+    if theta > T::EPSILON.sqrt().sqrt() {
+        let (s, c) = (theta.sin(), theta.cos());
+        (
+            s / theta,
+            (one - c) / theta2,
+            (theta - s) / (theta2 * theta),
+        )
+    } else {
+        let twenty_four = six * (two + two);
+        let hundred_twenty = twenty_four * (two + two + one);
+        (
+            one - theta2 / six,
+            one / two - theta2 / twenty_four,
+            one / six - theta2 / hundred_twenty,
+        )
+    }
+}
+
+/// Build a world-space picking ray through the pixel `mouse` of a viewport.
+///
+/// `mouse` is in pixels with the origin at the top-left corner of the viewport and
+/// `y` growing downwards, as window systems report it; `viewport` is its
+/// `(width, height)`. The ray starts on the near plane and its direction is
+/// normalized. Returns `None` if `proj * view` is singular.
+///
+/// # Example
+/// ```
+/// use mats::{Vec2, Vec3, graphics::{camera_ray, look_at, perspective}};
+///
+/// let eye = Vec3::<f64>::new([[0.0, 0.0, 5.0]]);
+/// let view = look_at(eye, Vec3::new([[0.0, 0.0, 0.0]]), Vec3::new([[0.0, 1.0, 0.0]]));
+/// let proj = perspective(1.0, 1.5, 0.1, 100.0);
+///
+/// let ray = camera_ray(Vec2::new([[300.0, 200.0]]), (600.0, 400.0), view, proj).unwrap();
+///
+/// // The center of the viewport looks straight ahead.
+/// let error = ray.direction - Vec3::new([[0.0, 0.0, -1.0]]);
+/// assert!(error.x().abs() < 1e-12 && error.y().abs() < 1e-12 && error.z().abs() < 1e-12);
+/// ```
+pub fn camera_ray<T: Float>(
+    mouse: Vec2<T>,
+    (width, height): (T, T),
+    view: Mat4<T>,
+    proj: Mat4<T>,
+) -> Option<Ray<T>> {
+    let inv = (proj * view).inverse()?;
+    let one = T::unit_one();
+    let two = one + one;
+    let x = two * mouse.x() / width - one;
+    let y = one - two * mouse.y() / height;
+
+    let unproject = |z: T| {
+        let p = inv * Vec4::new([[x, y, z, one]]);
+        p.xyz() / p.w()
+    };
+    let near = unproject(-one);
+    let direction = unproject(one) - near;
+    Some(Ray::new(
+        near,
+        direction / direction.inner_product(&direction).sqrt(),
+    ))
+}
+
+/// The largest pitch the cameras allow, 89°, keeping them away from the poles
+/// where the view direction is parallel to `+y` and `look_at` degenerates.
+#[inline]
+fn max_pitch<T: Float>() -> T {
+    let two = T::unit_one() + T::unit_one();
+    T::PI * (T::STRAIGHT - two) / (two * T::STRAIGHT)
+}
+
+/// Clamp a pitch angle to `±89°`.
+#[inline]
+fn clamp_pitch<T: Float>(pitch: Rad<T>) -> Rad<T> {
+    let max = max_pitch::<T>();
+    Rad(pitch.0.max(-max).min(max))
+}
+
+/// A camera orbiting `target` at `distance`, as used by model viewers.
+///
+/// At zero `yaw` and `pitch` the camera sits on the `+z` side of the target and
+/// looks down `-z`. A positive `yaw` moves it counterclockwise around `+y` (towards
+/// `+x`), and a positive `pitch` raises it above the target to look down. The pitch
+/// is kept within `±89°` by [`new`](Self::new) and [`rotate`](Self::rotate).
+///
+/// # Example
+/// ```
+/// use mats::{Deg, Vec3, graphics::{OrbitCamera, look_at}};
+///
+/// let mut camera = OrbitCamera::new(Vec3::new([[0.0, 1.0, 0.0]]), 5.0f64, Deg(0.0), Deg(0.0));
+/// assert_eq!(camera.eye_position(), Vec3::new([[0.0, 1.0, 5.0]]));
+///
+/// camera.rotate(Deg(30.0), Deg(120.0));
+/// camera.zoom(0.5);
+///
+/// assert!((camera.pitch.0 - 89f64.to_radians()).abs() < 1e-15);
+/// assert_eq!(camera.distance, 2.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitCamera<T> {
+    /// The point the camera looks at and orbits around.
+    pub target: Vec3<T>,
+    /// The distance from the target to the camera.
+    pub distance: T,
+    /// The rotation of the camera around `+y`.
+    pub yaw: Rad<T>,
+    /// The elevation of the camera above the target.
+    pub pitch: Rad<T>,
+}
+
+impl<T: Float> OrbitCamera<T> {
+    /// Create an orbit camera, clamping `pitch` to `±89°`.
+    #[inline]
+    pub fn new(target: Vec3<T>, distance: T, yaw: impl Angle<T>, pitch: impl Angle<T>) -> Self {
+        Self {
+            target,
+            distance,
+            yaw: yaw.to_rad(),
+            pitch: clamp_pitch(pitch.to_rad()),
+        }
+    }
+
+    /// Orbit the camera by `dyaw` and `dpitch`, clamping the pitch to `±89°`.
+    #[inline]
+    pub fn rotate(&mut self, dyaw: impl Angle<T>, dpitch: impl Angle<T>) {
+        self.yaw = self.yaw + dyaw.to_rad();
+        self.pitch = clamp_pitch(self.pitch + dpitch.to_rad());
+    }
+
+    /// Multiply the distance by `factor`, which must be positive; a factor below
+    /// one moves the camera closer.
+    #[inline]
+    pub fn zoom(&mut self, factor: T) {
+        self.distance = self.distance * factor;
+    }
+
+    /// Move the target in the camera plane by a mouse drag of `screen_delta` pixels
+    /// in a viewport of `(width, height)` pixels.
+    ///
+    /// `screen_delta` has `y` growing downwards, as window systems report it. The
+    /// scene follows the cursor, and dragging across the full viewport height moves
+    /// the target by `distance`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec2, Vec3, graphics::OrbitCamera};
+    ///
+    /// let mut camera = OrbitCamera::new(Vec3::new([[0.0, 0.0, 0.0]]), 4.0f64, 0.0, 0.0);
+    /// camera.pan(Vec2::new([[200.0, -100.0]]), (800.0, 400.0));
+    ///
+    /// // Dragging right and up moves the target left and down.
+    /// assert_eq!(camera.target, Vec3::new([[-2.0, -1.0, 0.0]]));
+    /// ```
+    #[inline]
+    pub fn pan(&mut self, screen_delta: Vec2<T>, (_width, height): (T, T)) {
+        let (right, up) = self.right_up();
+        let scale = self.distance / height;
+        self.target =
+            self.target - right * (screen_delta.x() * scale) + up * (screen_delta.y() * scale);
+    }
+
+    /// Return the position of the camera.
+    #[inline]
+    pub fn eye_position(&self) -> Vec3<T> {
+        let (Rad(yaw), Rad(pitch)) = (self.yaw, self.pitch);
+        let horizontal = self.distance * pitch.cos();
+        self.target
+            + Vec3::new([[
+                horizontal * yaw.sin(),
+                self.distance * pitch.sin(),
+                horizontal * yaw.cos(),
+            ]])
+    }
+
+    /// Return the world-to-camera matrix, `look_at(eye_position, target, +y)`.
+    #[inline]
+    pub fn view_matrix(&self) -> Mat4<T> {
+        let (o, i) = (T::zero(), T::unit_one());
+        look_at(self.eye_position(), self.target, Vec3::new([[o, i, o]]))
+    }
+
+    /// The unit right and up axes of the camera.
+    #[inline]
+    fn right_up(&self) -> (Vec3<T>, Vec3<T>) {
+        let (Rad(yaw), Rad(pitch)) = (self.yaw, self.pitch);
+        let (sy, cy, sp, cp) = (yaw.sin(), yaw.cos(), pitch.sin(), pitch.cos());
+        (
+            Vec3::new([[cy, T::zero(), -sy]]),
+            Vec3::new([[-sy * sp, cp, -cy * sp]]),
+        )
+    }
+}
+
+/// A first-person camera at `position` turned by `yaw` and `pitch`.
+///
+/// At zero `yaw` and `pitch` the camera looks down `-z` with `+x` to its right, like
+/// [`Vec3::from_yaw_pitch`]. A positive `yaw` turns left (counterclockwise around
+/// `+y`) and a positive `pitch` looks up; the pitch is kept within `±89°` by
+/// [`new`](Self::new) and [`rotate`](Self::rotate). The orientation is
+/// `rotation3_y(yaw) * rotation3_x(pitch)`.
+///
+/// # Example
+/// ```
+/// use mats::{Deg, Vec3, graphics::FpsCamera};
+///
+/// let mut camera = FpsCamera::new(Vec3::new([[0.0, 1.8, 0.0]]), 0.0f32, 0.0);
+/// assert_eq!(camera.forward(), Vec3::new([[0.0, 0.0, -1.0]]));
+///
+/// // Walking forward while looking up stays on the ground.
+/// camera.rotate(Deg(0.0), Deg(45.0));
+/// camera.move_relative(Vec3::new([[0.0, 0.0, -2.0]]));
+///
+/// assert_eq!(camera.position, Vec3::new([[0.0, 1.8, -2.0]]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FpsCamera<T> {
+    /// The position of the camera.
+    pub position: Vec3<T>,
+    /// The rotation of the camera around `+y`.
+    pub yaw: Rad<T>,
+    /// The rotation of the camera up from the horizon.
+    pub pitch: Rad<T>,
+}
+
+impl<T: Float> FpsCamera<T> {
+    /// Create a first-person camera, clamping `pitch` to `±89°`.
+    #[inline]
+    pub fn new(position: Vec3<T>, yaw: impl Angle<T>, pitch: impl Angle<T>) -> Self {
+        Self {
+            position,
+            yaw: yaw.to_rad(),
+            pitch: clamp_pitch(pitch.to_rad()),
+        }
+    }
+
+    /// Turn the camera by `dyaw` and `dpitch`, clamping the pitch to `±89°`.
+    #[inline]
+    pub fn rotate(&mut self, dyaw: impl Angle<T>, dpitch: impl Angle<T>) {
+        self.yaw = self.yaw + dyaw.to_rad();
+        self.pitch = clamp_pitch(self.pitch + dpitch.to_rad());
+    }
+
+    /// The unit direction the camera looks along.
+    #[inline]
+    pub fn forward(&self) -> Vec3<T> {
+        let (o, i) = (T::zero(), T::unit_one());
+        self.orientation() * Vec3::new([[o, o, -i]])
+    }
+
+    /// The unit direction to the right of the camera, always horizontal.
+    #[inline]
+    pub fn right(&self) -> Vec3<T> {
+        let (o, i) = (T::zero(), T::unit_one());
+        self.orientation() * Vec3::new([[i, o, o]])
+    }
+
+    /// The unit direction above the camera, perpendicular to `forward` and `right`.
+    #[inline]
+    pub fn up(&self) -> Vec3<T> {
+        let (o, i) = (T::zero(), T::unit_one());
+        self.orientation() * Vec3::new([[o, i, o]])
+    }
+
+    /// Move the camera by `delta` in its walking frame: `x` to the right, `y` up
+    /// and `-z` forward, turned by the yaw only so that pitch does not lift the
+    /// camera off the ground.
+    #[inline]
+    pub fn move_relative(&mut self, delta: Vec3<T>) {
+        self.position += rotation3_y(self.yaw) * delta;
+    }
+
+    /// Return the world-to-camera matrix, `look_to(position, forward, up)`.
+    #[inline]
+    pub fn view_matrix(&self) -> Mat4<T> {
+        look_to(self.position, self.forward(), self.up())
+    }
+
+    /// The camera-to-world rotation.
+    #[inline]
+    fn orientation(&self) -> Mat3<T> {
+        rotation3_y(self.yaw) * rotation3_x(self.pitch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orient2d_collinear() {
+        let p = |x: f64, y: f64| Vec2::new([[x, y]]);
+        assert_eq!(
+            orient2d(p(0.0, 0.0), p(1.0, 1.0), p(3.0, 3.0)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            orient2d(p(1.0, 1.0), p(1.0, 1.0), p(2.0, 5.0)),
+            Ordering::Equal
+        );
+
+        let q = |x: i32, y: i32| Vec2::new([[x, y]]);
+        assert_eq!(orient2d(q(-2, -4), q(1, 2), q(3, 6)), Ordering::Equal);
+        assert_eq!(orient2d(q(0, 0), q(1, 0), q(0, 1)), Ordering::Greater);
+        assert_eq!(
+            orient2d(
+                q(i32::MIN, i32::MIN),
+                q(i32::MAX, i32::MIN),
+                q(i32::MIN, i32::MAX)
+            ),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_orient2d_i64_limits() {
+        let p = |x: i64, y: i64| Vec2::new([[x, y]]);
+        let (min, max) = (i64::MIN, i64::MAX);
+
+        assert_eq!(orient2d(p(min, min), p(max, max), p(0, 0)), Ordering::Equal);
+        assert_eq!(
+            orient2d(p(min, min), p(max, max), p(-1, -1)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            orient2d(p(min, min), p(max, max), p(-1, 0)),
+            Ordering::Greater
+        );
+        assert_eq!(orient2d(p(min, min), p(max, max), p(0, -1)), Ordering::Less);
+        assert_eq!(
+            orient2d(p(min, max), p(max, min), p(min, min)),
+            Ordering::Less
+        );
+        assert_eq!(
+            orient2d(p(max, min), p(min, max), p(min, min)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_orient3d_exact_integers() {
+        let p = |x: i32, y: i32, z: i32| Vec3::new([[x, y, z]]);
+        let (min, max) = (i32::MIN, i32::MAX);
+
+        let (a, b, c) = (p(min, min, 0), p(max, min, 0), p(min, max, 0));
+        assert_eq!(orient3d(a, b, c, p(0, 0, max)), Ordering::Greater);
+        assert_eq!(orient3d(a, b, c, p(0, 0, min)), Ordering::Less);
+        assert_eq!(orient3d(a, b, c, p(max, max, 0)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_barycentric() {
+        let a = Vec2::new([[1.0, -1.0]]);
+        let b = Vec2::new([[4.0, 0.5]]);
+        let c = Vec2::new([[-2.0, 3.0]]);
+
+        assert_eq!(barycentric(a, a, b, c), Vec3::new([[1.0, 0.0, 0.0]]));
+        assert_eq!(barycentric(b, a, b, c), Vec3::new([[0.0, 1.0, 0.0]]));
+        assert_eq!(barycentric(c, a, b, c), Vec3::new([[0.0, 0.0, 1.0]]));
+
+        let centroid = barycentric((a + b + c) / 3.0, a, b, c);
+        for i in 0..3 {
+            assert!((centroid[i] - 1.0 / 3.0).abs() < 1e-12);
+        }
+
+        let p = Vec2::new([[0.7, 1.3]]);
+        let uvw = barycentric(p, a, b, c);
+        let q = a * uvw.x() + b * uvw.y() + c * uvw.z();
+        assert!((q - p).x().abs() < 1e-12 && (q - p).y().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_barycentric3d() {
+        let a = Vec3::new([[1.0, 0.0, 2.0]]);
+        let b = Vec3::new([[0.0, 3.0, -1.0]]);
+        let c = Vec3::new([[2.0, 2.0, 2.0]]);
+
+        let p = a * 0.2 + b * 0.3 + c * 0.5;
+        let uvw = try_barycentric3d(p, a, b, c).unwrap();
+        let expected = [0.2, 0.3, 0.5];
+        for i in 0..3 {
+            assert!((uvw[i] - expected[i]).abs() < 1e-12);
+        }
+
+        assert_eq!(try_barycentric3d(p, a, b, a * 2.0 - b), None);
+    }
+
+    #[test]
+    fn test_plane_distance_signs() {
+        let plane = Plane::from_points(
+            Vec3::new([[1.0, 0.0, 0.0]]),
+            Vec3::new([[0.0, 1.0, 0.0]]),
+            Vec3::new([[0.0, 0.0, 1.0]]),
+        );
+        let above = Vec3::new([[1.0, 1.0, 1.0]]);
+        let below = Vec3::new([[0.0, 0.0, 0.0]]);
+
+        assert!(plane.signed_distance(above) > 0.0);
+        assert!(plane.signed_distance(below) < 0.0);
+        assert!((plane.signed_distance(below) + 1.0 / 3.0f64.sqrt()).abs() < 1e-12);
+        assert!(plane.signed_distance(plane.project_point(above)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_three_plane_intersection() {
+        let point = Vec3::new([[1.0, -2.0, 3.0]]);
+        let a = Plane::from_point_normal(point, Vec3::new([[1.0, 1.0, 0.0]]));
+        let b = Plane::from_point_normal(point, Vec3::new([[0.0, 1.0, 1.0]]));
+        let c = Plane::from_point_normal(point, Vec3::new([[0.0, 0.0, 1.0]]));
+
+        let line = a.intersect_plane(&b).unwrap();
+        assert!(a.signed_distance(line.origin).abs() < 1e-12);
+        assert!(b.signed_distance(line.origin).abs() < 1e-12);
+
+        // The line origin lies below `c`, and its direction points upwards.
+        let hit = line.at(c.intersect_ray(&line).unwrap());
+        let error = hit - point;
+        assert!(error.inner_product(&error) < 1e-20);
+
+        assert_eq!(a.intersect_plane(&a), None);
+    }
+
+    #[test]
+    fn test_plane_transform_non_uniform_scale() {
+        let plane = Plane::from_points(
+            Vec3::new([[1.0, 0.0, 0.0]]),
+            Vec3::new([[0.0, 1.0, 0.0]]),
+            Vec3::new([[0.0, 1.0, 1.0]]),
+        );
+        let m = translate3d(Vec3::new([[0.5, 0.0, -1.0]])) * scale3d(Vec3::new([[2.0, 1.0, 3.0]]));
+        let moved = plane.transformed(&m);
+
+        for p in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.5, 0.5, 4.0]] {
+            let p = (m * Vec4::new([[p[0], p[1], p[2], 1.0]])).xyz();
+            assert!(moved.signed_distance(p).abs() < 1e-12);
+        }
+        let expected = Vec3::new([[1.0, 2.0, 0.0]]) / 5.0f64.sqrt();
+        let error = moved.normal - expected;
+        assert!(error.inner_product(&error) < 1e-20);
+
+        let mirrored = plane.transformed(&scale3d(Vec3::new([[-1.0, 1.0, 1.0]])));
+        let outside = Vec3::new([[-1.0, 1.0, 0.0]]);
+        assert!(mirrored.signed_distance(outside) > 0.0);
+    }
+
+    #[test]
+    fn test_rotations_match_homogeneous() {
+        fn block3(m: Mat3<f64>) -> [[f64; 2]; 2] {
+            let d = m.raw_data();
+            [[d[0][0], d[0][1]], [d[1][0], d[1][1]]]
+        }
+        fn block4(m: Mat4<f64>) -> [[f64; 3]; 3] {
+            let d = m.raw_data();
+            core::array::from_fn(|j| core::array::from_fn(|i| d[j][i]))
+        }
+
+        let axis = Vec3::new([[1.0, -2.0, 0.5]]);
+        for angle in [0.0, 0.3, 1.0, -2.5, 4.0] {
+            assert_eq!(&block3(rotate2d(angle)), rotation2(angle).raw_data());
+            assert_eq!(&block4(rotate3d_x(angle)), rotation3_x(angle).raw_data());
+            assert_eq!(&block4(rotate3d_y(angle)), rotation3_y(angle).raw_data());
+            assert_eq!(&block4(rotate3d_z(angle)), rotation3_z(angle).raw_data());
+            assert_eq!(
+                &block4(rotate3d(axis, angle)),
+                rotation3(axis, angle).raw_data()
+            );
+        }
+    }
+
+    #[test]
+    fn test_angle_types() {
+        use crate::Deg;
+
+        let pi = core::f64::consts::PI;
+        assert_eq!(rotate2d(Deg(90.0)), rotate2d(Rad(pi / 2.0)));
+        assert_eq!(rotate2d(Rad(pi / 2.0)), rotate2d(pi / 2.0));
+        assert_eq!(
+            rotate3d(Vec3::new([[1.0, 1.0, 0.0]]), Deg(30.0)),
+            rotate3d(Vec3::new([[1.0, 1.0, 0.0]]), pi / 6.0)
+        );
+        assert_eq!(
+            perspective(Deg(60.0), 1.5, 0.1, 100.0),
+            perspective(Rad(pi / 3.0), 1.5, 0.1, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_splines_interpolate_endpoints() {
+        let p0 = Vec3::new([[0.1, -2.0, 3.7]]);
+        let p1 = Vec3::new([[1.3, 0.7, -0.2]]);
+        let p2 = Vec3::new([[2.9, 1.1, 5.5]]);
+        let p3 = Vec3::new([[-4.0, 0.3, 1.0]]);
+
+        assert_eq!(quadratic_bezier(p0, p1, p2, 0.0), p0);
+        assert_eq!(quadratic_bezier(p0, p1, p2, 1.0), p2);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 1.0), p3);
+        assert_eq!(hermite(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(hermite(p0, p1, p2, p3, 1.0), p2);
+        assert_eq!(catmull_rom(p0, p1, p2, p3, 0.0), p1);
+        assert_eq!(catmull_rom(p0, p1, p2, p3, 1.0), p2);
+    }
+
+    #[test]
+    fn test_spline_derivatives() {
+        let p0 = Vec2::new([[0.0, 0.0]]);
+        let p1 = Vec2::new([[1.0, 3.0]]);
+        let p2 = Vec2::new([[4.0, -1.0]]);
+        let p3 = Vec2::new([[5.0, 2.0]]);
+        let h = 1e-6;
+
+        for t in [0.1, 0.5, 0.8] {
+            let checks = [
+                (
+                    cubic_bezier_derivative(p0, p1, p2, p3, t),
+                    (cubic_bezier(p0, p1, p2, p3, t + h) - cubic_bezier(p0, p1, p2, p3, t - h)),
+                ),
+                (
+                    quadratic_bezier_derivative(p0, p1, p2, t),
+                    (quadratic_bezier(p0, p1, p2, t + h) - quadratic_bezier(p0, p1, p2, t - h)),
+                ),
+                (
+                    catmull_rom_derivative(p0, p1, p2, p3, t),
+                    (catmull_rom(p0, p1, p2, p3, t + h) - catmull_rom(p0, p1, p2, p3, t - h)),
+                ),
+            ];
+            for (exact, diff) in checks {
+                let error = exact - diff / (2.0 * h);
+                assert!(error.x().abs() < 1e-6 && error.y().abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate3d_degenerate_axis() {
+        let zero = Vec3::new([[0.0f32, 0.0, 0.0]]);
+        let tiny = Vec3::new([[1e-40f32, 0.0, -1e-40]]);
+
+        for axis in [zero, tiny] {
+            assert_eq!(rotate3d(axis, 1.0), Mat4::I());
+            assert_eq!(rotation3(axis, 1.0), Mat3::I());
+            assert_eq!(try_rotate3d(axis, 1.0), None);
+        }
+        let nan = Vec3::new([[f32::NAN, 0.0, 1.0]]);
+        let inf = Vec3::new([[0.0, f32::INFINITY, 0.0]]);
+        for axis in [nan, inf] {
+            assert_eq!(try_rotation3(axis, 1.0), None);
+            assert_eq!(try_rotate3d(axis, 1.0), None);
+            assert_eq!(rotate3d(axis, 1.0), Mat4::I());
+        }
+        assert_eq!(
+            try_rotate3d(Vec3::new([[0.0, 0.0, 3.0]]), 1.0),
+            Some(rotate3d_z(1.0))
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_rotate3d_no_norm_zero_axis() {
+        unsafe { rotate3d_no_norm(Vec3::new([[0.0f32, 0.0, 0.0]]), 1.0) };
+    }
+
+    #[test]
+    fn test_try_projection_errors() {
+        let pi = core::f64::consts::PI;
+        assert_eq!(
+            try_perspective(0.0, 1.0, 0.1, 10.0),
+            Err(ProjectionError::FieldOfView)
+        );
+        assert_eq!(
+            try_perspective(pi, 1.0, 0.1, 10.0),
+            Err(ProjectionError::FieldOfView)
+        );
+        assert_eq!(
+            try_perspective(1.0, 0.0, 0.1, 10.0),
+            Err(ProjectionError::AspectRatio)
+        );
+        assert_eq!(
+            try_perspective(1.0, f64::NAN, 0.1, 10.0),
+            Err(ProjectionError::AspectRatio)
+        );
+        assert_eq!(
+            try_perspective(1.0, 1.0, 0.0, 10.0),
+            Err(ProjectionError::NearPlane)
+        );
+        assert_eq!(
+            try_perspective(1.0, 1.0, 2.0, 2.0),
+            Err(ProjectionError::DepthRange)
+        );
+        assert_eq!(
+            try_perspective(1.0, 1.5, 0.1, 100.0),
+            Ok(perspective(1.0, 1.5, 0.1, 100.0))
+        );
+
+        let planes = (-2.0, 1.0, 3.0, -1.0);
+        assert_eq!(
+            try_orthographic((1.0, 1.0, 1.0, -1.0), 0.0, 1.0),
+            Err(ProjectionError::Width)
+        );
+        assert_eq!(
+            try_orthographic((-1.0, 1.0, 1.0, 1.0), 0.0, 1.0),
+            Err(ProjectionError::Height)
+        );
+        assert_eq!(
+            try_orthographic(planes, 1.0, 1.0),
+            Err(ProjectionError::DepthRange)
+        );
+        assert_eq!(
+            try_orthographic(planes, -1.0, 5.0),
+            Ok(orthographic(planes, -1.0, 5.0))
+        );
+        assert_eq!(
+            try_orthographic((f64::NAN, 1.0, 1.0, -1.0), 0.0, 1.0),
+            Err(ProjectionError::Width)
+        );
+        assert_eq!(
+            try_orthographic((-1.0, f64::INFINITY, 1.0, -1.0), 0.0, 1.0),
+            Err(ProjectionError::Height)
+        );
+        assert_eq!(
+            try_orthographic(planes, 0.0, f64::NEG_INFINITY),
+            Err(ProjectionError::DepthRange)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "aspect ratio")]
+    fn test_perspective_panics_in_debug() {
+        perspective(1.0, 0.0, 0.1, 10.0);
+    }
+
+    #[test]
+    fn test_look_at_inverse() {
+        let eye = Vec3::new([[1.5, -2.0, 4.0]]);
+        let center = Vec3::new([[-0.5, 1.0, 0.25]]);
+        let up = Vec3::new([[0.1, 1.0, 0.2]]);
+
+        let view = look_at(eye, center, up);
+        let camera = look_at_inverse(eye, center, up);
+        for product in [view * camera, camera * view] {
+            let error = product - Mat4::I();
+            for i in 0..4 {
+                for j in 0..4 {
+                    assert!(error[(i, j)].abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_camera_ray() {
+        let close = |a: Vec3<f64>, b: Vec3<f64>| {
+            let d = a - b;
+            d.inner_product(&d) < 1e-18
+        };
+        let eye = Vec3::new([[1.0, 2.0, 3.0]]);
+        let center = Vec3::new([[-1.0, 0.5, -2.0]]);
+        let up = Vec3::new([[0.0, 1.0, 0.0]]);
+        let (fov, aspect, near) = (1.2, 1.6, 0.5);
+        let view = look_at(eye, center, up);
+        let proj = perspective(fov, aspect, near, 50.0);
+        let viewport = (800.0, 500.0);
+
+        let forward = center - eye;
+        let forward = forward / forward.inner_product(&forward).sqrt();
+        let ray = camera_ray(Vec2::new([[400.0, 250.0]]), viewport, view, proj).unwrap();
+        assert!(close(ray.direction, forward));
+        assert!(close(ray.origin, eye + forward * near));
+
+        // The top-left pixel corner is the top-left corner of the near plane.
+        let half_height = near * (fov / 2.0).tan();
+        let corner = Vec4::new([[-half_height * aspect, half_height, -near, 1.0]]);
+        let corner = (look_at_inverse(eye, center, up) * corner).xyz();
+        let ray = camera_ray(Vec2::new([[0.0, 0.0]]), viewport, view, proj).unwrap();
+        assert!(close(ray.origin, corner));
+
+        assert_eq!(
+            camera_ray(Vec2::new([[0.0, 0.0]]), viewport, view, Mat4::<f64>::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn test_so3_round_trip() {
+        let axes = [
+            Vec3::new([[1.0, 0.0, 0.0]]),
+            Vec3::new([[0.0, -1.0, 0.0]]),
+            Vec3::new([[1.0, 2.0, -2.0]]) / 3.0,
+            Vec3::new([[-0.6, 0.0, 0.8]]),
+        ];
+        let pi = core::f64::consts::PI;
+        for axis in axes {
+            for angle in [1e-8, 1e-5, 1e-3, 0.1, 1.0, 2.0, 3.0, pi - 1e-2, pi - 1e-4] {
+                let w = axis * angle;
+                let r = exp_so3(w);
+
+                let expected = rotation3(axis, angle);
+                let error = r - expected;
+                assert!(error.raw_data().iter().flatten().all(|e| e.abs() < 1e-12));
+
+                let error = log_so3(r) - w;
+                assert!(
+                    error.raw_data()[0].iter().all(|e| e.abs() < 1e-9),
+                    "angle {angle}: {error:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_se3_round_trip() {
+        let v = Vec3::new([[0.5, -1.0, 2.0]]);
+        let axis = Vec3::new([[2.0, -1.0, 2.0]]) / 3.0;
+        for angle in [0.0, 1e-8, 1e-3, 0.5, 2.5, 3.1] {
+            let w = axis * angle;
+            let m = exp_se3(w, v);
+
+            let r = exp_so3(w);
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert_eq!(m[(i, j)], r[(i, j)]);
+                }
+            }
+
+            let (w2, v2) = log_se3(m);
+            assert!((w2 - w).raw_data()[0].iter().all(|e| e.abs() < 1e-9));
+            assert!((v2 - v).raw_data()[0].iter().all(|e| e.abs() < 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_orbit_camera() {
+        let target = Vec3::new([[1.0, 2.0, -3.0]]);
+        let mut camera = OrbitCamera::new(target, 4.0f64, 0.3, 0.2);
+
+        for _ in 0..10 {
+            camera.rotate(0.25, 0.5);
+            assert!(camera.pitch.0 <= max_pitch());
+            assert!(camera.view_matrix().is_finite());
+        }
+        camera.rotate(0.0, -10.0);
+        assert_eq!(camera.pitch.0, -max_pitch::<f64>());
+
+        camera.rotate(0.0, 1.0);
+        let eye = camera.eye_position();
+        assert!(((eye - target).inner_product(&(eye - target)) - 16.0).abs() < 1e-12);
+        assert_eq!(
+            camera.view_matrix(),
+            look_at(eye, target, Vec3::new([[0.0, 1.0, 0.0]]))
+        );
+
+        // Panning keeps the target at the same depth in camera space, and moves
+        // the eye along with it.
+        let view = camera.view_matrix();
+        camera.pan(Vec2::new([[30.0, 45.0]]), (640.0, 480.0));
+        let moved = view * Vec4::from((camera.target, 1.0));
+        assert!((moved.z() + 4.0).abs() < 1e-12);
+        assert!(moved.x() < 0.0 && moved.y() > 0.0);
+        let offset = camera.eye_position() - eye - (camera.target - target);
+        assert!(offset.inner_product(&offset) < 1e-24);
+    }
+
+    #[test]
+    fn test_pixel_projection_corners() {
+        let (w, h) = (1920.0f32, 1080.0);
+        let pixels = [(0.0, 0.0), (w, 0.0), (0.0, h), (w, h)];
+        for y_down in [true, false] {
+            let to_ndc = ortho_pixel(w, h, y_down);
+            let to_ndc3d = ortho_pixel3d(w, h, y_down);
+            let to_pixel = ndc_to_pixel((w, h), y_down);
+            for (x, y) in pixels {
+                let top = if y_down { y == 0.0 } else { y == h };
+                let ndc = Vec3::new([[
+                    if x == 0.0 { -1.0 } else { 1.0 },
+                    if top { 1.0 } else { -1.0 },
+                    1.0,
+                ]]);
+                assert_eq!(to_ndc * Vec3::new([[x, y, 1.0]]), ndc, "{y_down}");
+                assert_eq!(
+                    to_ndc3d * Vec4::new([[x, y, 0.0, 1.0]]),
+                    Vec4::new([[ndc.x(), ndc.y(), 0.0, 1.0]])
+                );
+                assert_eq!(to_pixel * ndc, Vec3::new([[x, y, 1.0]]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_frustum_culling() {
+        use crate::Deg;
+
+        let frustum = Frustum::from_view_proj(&perspective(Deg(90.0), 1.0, 1.0, 10.0));
+        let point = |z: f64| Vec3::new([[0.0, 0.0, z]]);
+
+        // Behind the camera, straddling the near plane, and in the middle.
+        assert_eq!(frustum.test_sphere(point(0.0), 0.5), Containment::Outside);
+        assert_eq!(
+            frustum.test_sphere(point(-1.0), 0.5),
+            Containment::Intersecting
+        );
+        assert_eq!(frustum.test_sphere(point(-5.0), 1.0), Containment::Inside);
+        assert_eq!(frustum.test_sphere(point(-12.0), 1.0), Containment::Outside);
+        assert_eq!(
+            frustum.test_sphere(Vec3::new([[5.0, 0.0, -5.0]]), 0.5),
+            Containment::Intersecting
+        );
+
+        let cube = |c: Vec3<f64>, h: f64| {
+            Aabb::new(c - Vec3::new([[h, h, h]]), c + Vec3::new([[h, h, h]]))
+        };
+        assert_eq!(
+            frustum.test_aabb(&cube(point(0.0), 0.5)),
+            Containment::Outside
+        );
+        assert_eq!(
+            frustum.test_aabb(&cube(point(-1.0), 0.5)),
+            Containment::Intersecting
+        );
+        assert_eq!(
+            frustum.test_aabb(&cube(point(-5.0), 1.0)),
+            Containment::Inside
+        );
+        assert_eq!(
+            frustum.test_aabb(&cube(Vec3::new([[-9.0, 0.0, -5.0]]), 1.0)),
+            Containment::Outside
+        );
+
+        // The planes follow the camera.
+        let view = look_at(point(5.0), point(0.0), Vec3::new([[0.0, 1.0, 0.0]]));
+        let proj = perspective(Deg(90.0), 1.0, 1.0, 10.0);
+        let frustum = Frustum::from_view_proj(&(proj * view));
+        assert_eq!(frustum.test_sphere(point(0.0), 1.0), Containment::Inside);
+        assert_eq!(frustum.test_sphere(point(5.0), 0.5), Containment::Outside);
+    }
+
+    #[test]
+    fn test_shadow_matrix() {
+        let plane = Vec4::new([[1.0, 2.0, -2.0, 3.0]]);
+        let on_plane = |p: Vec4<f64>| (plane.inner_product(&p) / p.w()).abs() < 1e-12;
+        let project = |m: Mat4<f64>, p: Vec4<f64>| {
+            let q = m * p;
+            q / q.w()
+        };
+
+        let lights = [
+            Vec4::new([[4.0, 5.0, -6.0, 1.0]]),
+            Vec4::new([[0.5, 1.0, 2.0, 0.0]]),
+        ];
+        for light in lights {
+            let m = shadow_matrix(plane, light);
+
+            let fixed = Vec4::new([[1.0, 0.0, 2.0, 1.0]]);
+            assert!(on_plane(fixed));
+            let error = project(m, fixed) - fixed;
+            assert!(error.inner_product(&error) < 1e-24, "{light:?}");
+
+            for p in [[0.0, 0.0, 0.0], [1.0, -2.0, 3.5], [-7.0, 4.0, 0.25]] {
+                let p = Vec4::from((Vec3::new([p]), 1.0));
+                let shadow = project(m, p);
+                assert!(on_plane(shadow), "{light:?} {p:?}");
+
+                // The shadow lies on the line from the light through the point.
+                let towards = (shadow - p).xyz();
+                let to_light = if light.w() == 0.0 {
+                    light.xyz()
+                } else {
+                    light.xyz() - p.xyz()
+                };
+                let cross = towards.cross(&to_light);
+                assert!(cross.inner_product(&cross) < 1e-18, "{light:?} {p:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_axis_conventions() {
+        let p = Vec4::new([[1.0, 2.0, 3.0, 1.0]]);
+        let to_y_up: Mat4<f64> = z_up_to_y_up();
+        let to_z_up: Mat4<f64> = y_up_to_z_up();
+        let flip: Mat4<f64> = flip_handedness();
+
+        assert_eq!(to_y_up * p, Vec4::new([[1.0, 3.0, -2.0, 1.0]]));
+        assert_eq!(to_z_up * p, Vec4::new([[1.0, -3.0, 2.0, 1.0]]));
+        assert_eq!(to_z_up * to_y_up, Mat4::I());
+        assert_eq!(to_y_up * to_z_up, Mat4::I());
+        assert_eq!(flip * flip, Mat4::I());
+
+        for m in [mirror_x(), mirror_y(), mirror_z(), flip] {
+            assert_eq!(m.determinant_sign(), -1.0);
+            assert_eq!((m * translate3d(p.xyz())).determinant_sign(), -1.0);
+        }
+        assert_eq!(to_y_up.determinant_sign(), 1.0);
+        assert_eq!((mirror_x::<f64>() * mirror_y()).determinant_sign(), 1.0);
+    }
+
+    #[test]
+    fn test_interpolate_rigid() {
+        let axis = Vec3::new([[1.0, 2.0, -2.0]]) / 3.0;
+        let a = translate3d(Vec3::new([[1.0, -2.0, 0.5]])) * rotate3d(axis, 0.3);
+        let b = translate3d(Vec3::new([[-3.0, 4.0, 2.0]]))
+            * rotate3d(Vec3::new([[0.0, 1.0, 0.0]]), 2.5);
+
+        assert_eq!(interpolate_rigid(&a, &b, 0.0), a);
+        assert_eq!(interpolate_rigid(&a, &b, 1.0), b);
+
+        for i in 1..10 {
+            let t = i as f64 / 10.0;
+            let m = interpolate_rigid(&a, &b, t);
+            let r = Mat3::new(core::array::from_fn(|j| {
+                core::array::from_fn(|i| m[(i, j)])
+            }));
+            let error = r.T() * r - Mat3::I();
+            assert!(
+                error.raw_data().iter().flatten().all(|e| e.abs() < 1e-12),
+                "{t}"
+            );
+            assert!((m.determinant() - 1.0).abs() < 1e-12);
+            assert_eq!(m[(3, 3)], 1.0);
+        }
+
+        // Half way through a pure rotation is the half-angle rotation.
+        let mid = interpolate_rigid(&Mat4::I(), &rotate3d(axis, 1.2), 0.5);
+        let error = mid - rotate3d(axis, 0.6);
+        assert!(error.raw_data().iter().flatten().all(|e| e.abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_decompose_2d_round_trip() {
+        use core::f64::consts::PI;
+
+        let close = |a: Mat3<f64>, b: Mat3<f64>| {
+            (a - b).raw_data().iter().flatten().all(|e| e.abs() < 1e-12)
+        };
+        let shear =
+            |k: f64| Mat3::from_rows_array_2d([[1.0, k, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        let t = Vec2::new([[3.0, -4.0]]);
+
+        // One angle in each quadrant, with and without a mirror and skew.
+        for angle in [0.4, 2.0, -2.5, -0.7] {
+            for s in [[2.0, 0.5], [1.5, -3.0]] {
+                for k in [0.0, 0.75] {
+                    let s = Vec2::new([s]);
+                    let m = translate2d(t) * rotate2d(angle) * shear(k) * scale2d(s);
+
+                    let (t2, Rad(angle2), s2, k2) = m.decompose_2d().unwrap();
+                    let error = [t2.x() - t.x(), t2.y() - t.y(), angle2 - angle, k2 - k];
+                    assert!(error.iter().all(|e| e.abs() < 1e-12), "{angle} {s:?} {k}");
+                    assert!((s2.x() - s.x()).abs() < 1e-12 && (s2.y() - s.y()).abs() < 1e-12);
+                    assert!(close(
+                        translate2d(t2) * rotate2d(angle2) * shear(k2) * scale2d(s2),
+                        m
+                    ));
+                }
+            }
+        }
+
+        // Negating both axes is a half turn.
+        let m: Mat3<f64> = rotate2d(0.5) * scale2d(Vec2::new([[-2.0, -3.0]]));
+        let (_, Rad(angle), s, _) = m.decompose_2d().unwrap();
+        assert!((angle - (0.5 - PI)).abs() < 1e-12);
+        assert!((s.x() - 2.0).abs() < 1e-12 && (s.y() - 3.0).abs() < 1e-12);
+
+        assert_eq!(scale2d(Vec2::new([[0.0, 1.0]])).decompose_2d(), None);
+        assert_eq!(scale2d(Vec2::new([[1.0, 0.0]])).decompose_2d(), None);
+        let mut projective = Mat3::<f64>::I();
+        projective[(2, 0)] = 0.5;
+        assert_eq!(projective.decompose_2d(), None);
+    }
+
+    #[test]
+    fn test_vec4_planes() {
+        let (a, b, c) = (
+            Vec3::new([[1.0, 0.0, 2.0]]),
+            Vec3::new([[0.0, 3.0, -1.0]]),
+            Vec3::new([[2.0, 1.0, 1.0]]),
+        );
+        let plane = plane_from_points(a, b, c);
+        let scaled = plane * -4.0;
+        assert_eq!(plane.xyz(), Plane::from_points(a, b, c).normal);
+
+        let front = a + plane.xyz() * 2.0;
+        for p in [a, b, c] {
+            assert!(plane.plane_distance(p).abs() < 1e-12);
+            assert!(scaled.normalize_plane().plane_distance(p).abs() < 1e-12);
+        }
+        assert!((plane.plane_distance(front) - 2.0).abs() < 1e-12);
+        // Scaling by a negative factor flips the front side.
+        assert!((scaled.plane_distance(front) + 2.0).abs() < 1e-12);
+
+        let normalized = scaled.normalize_plane();
+        assert!((normalized.xyz().inner_product(&normalized.xyz()) - 1.0).abs() < 1e-12);
+        let error = normalized + plane;
+        assert!(error.inner_product(&error) < 1e-24);
+    }
+
+    #[test]
+    fn test_fps_camera() {
+        use crate::Deg;
+
+        let close = |a: Vec3<f64>, b: Vec3<f64>| (a - b).inner_product(&(a - b)) < 1e-24;
+
+        let mut camera = FpsCamera::new(Vec3::new([[1.0, 2.0, 3.0]]), 0.0, 0.0);
+        assert_eq!(camera.forward(), Vec3::new([[0.0, 0.0, -1.0]]));
+        assert_eq!(camera.right(), Vec3::new([[1.0, 0.0, 0.0]]));
+        assert_eq!(camera.up(), Vec3::new([[0.0, 1.0, 0.0]]));
+
+        camera.rotate(Deg(90.0), Deg(30.0));
+        assert!(close(camera.right(), Vec3::new([[0.0, 0.0, -1.0]])));
+        assert!(close(
+            camera.forward(),
+            Vec3::from_yaw_pitch(Deg(90.0), Deg(30.0))
+        ));
+        camera.move_relative(Vec3::new([[0.0, 0.0, -2.0]]));
+        assert!(close(camera.position, Vec3::new([[-1.0, 2.0, 3.0]])));
+
+        for _ in 0..10 {
+            camera.rotate(0.0, Deg(25.0));
+            assert!(camera.pitch.0 <= 89f64.to_radians() + 1e-15);
+        }
+        camera.rotate(0.0, Deg(-500.0));
+        assert!(camera.pitch.0 >= -89f64.to_radians() - 1e-15);
+
+        let view = camera.view_matrix();
+        let target = camera.position + camera.forward();
+        let expected = look_at(camera.position, target, Vec3::new([[0.0, 1.0, 0.0]]));
+        assert!(
+            (view - expected)
+                .raw_data()
+                .iter()
+                .flatten()
+                .all(|e| e.abs() < 1e-12)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "strict-math", debug_assertions))]
+    #[should_panic(expected = "look_at: non-finite element")]
+    fn test_strict_math_look_at() {
+        let eye = Vec3::new([[1.0, 2.0, 3.0]]);
+        look_at(eye, eye, Vec3::new([[0.0, 1.0, 0.0]]));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::{Float, Mat, Vec3, Vec4};
+
+/// The integer `n`, built exactly from `T::unit_one()` by doubling.
+#[inline]
+fn integer<T: Float>(n: u32) -> T {
+    (0..u32::BITS - n.leading_zeros())
+        .rev()
+        .fold(T::zero(), |acc, bit| {
+            let acc = acc + acc;
+            if (n >> bit) & 1 == 1 {
+                acc + T::unit_one()
+            } else {
+                acc
+            }
+        })
+}
+
+/// The fraction `n / d`.
+///
+/// It is correctly rounded like the equivalent decimal literal as long as `n` and
+/// `d` are exact in `T`; larger integers round, and overflow where `T` has a small
+/// range such as `f16`.
+#[inline]
+fn ratio<T: Float>(n: u32, d: u32) -> T {
+    integer::<T>(n) / integer(d)
+}
+
+#[inline]
+fn map3<T: Float>(c: Vec3<T>, f: impl Fn(T) -> T) -> Vec3<T> {
+    Mat {
+        data: [[f(c.x()), f(c.y()), f(c.z())]],
+    }
+}
+
+#[inline]
+fn with_alpha<T: Float>(c: Vec4<T>, f: impl Fn(Vec3<T>) -> Vec3<T>) -> Vec4<T> {
+    Vec4::from((f(c.xyz()), c.w()))
+}
+
+/// Decode sRGB-encoded components to linear light with the exact piecewise sRGB
+/// curve: `c / 12.92` up to `0.04045`, `((c + 0.055) / 1.055)^2.4` above.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::color::srgb_to_linear};
+///
+/// let linear = srgb_to_linear(Vec3::new([[0.0, 0.04045, 1.0f64]]));
+///
+/// assert_eq!(linear.x(), 0.0);
+/// assert_eq!(linear.y(), 0.04045 / 12.92);
+/// assert!((linear.z() - 1.0).abs() < 1e-15);
+/// ```
+#[inline]
+pub fn srgb_to_linear<T: Float>(c: Vec3<T>) -> Vec3<T> {
+    let (threshold, slope) = (ratio::<T>(809, 20000), ratio::<T>(1292, 100));
+    let (offset, scale) = (ratio::<T>(11, 200), ratio::<T>(211, 200));
+    let gamma = ratio::<T>(12, 5);
+    map3(c, |c| {
+        if c <= threshold {
+            c / slope
+        } else {
+            ((c + offset) / scale).powf(gamma)
+        }
+    })
+}
+
+/// Encode linear light to sRGB components, the inverse of [`srgb_to_linear`]:
+/// `12.92 c` up to `0.0031308`, `1.055 c^(1 / 2.4) - 0.055` above.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::color::linear_to_srgb};
+///
+/// let srgb = linear_to_srgb(Vec3::new([[0.0, 0.0031308, 0.2140411f64]]));
+///
+/// assert_eq!(srgb.x(), 0.0);
+/// assert_eq!(srgb.y(), 0.0031308 * 12.92);
+/// assert!((srgb.z() - 0.5).abs() < 1e-6);
+/// ```
+#[inline]
+pub fn linear_to_srgb<T: Float>(c: Vec3<T>) -> Vec3<T> {
+    // `0.0031308` in two steps, since `10_000_000` overflows `f16`.
+    let threshold = ratio::<T>(31308, 10_000) / integer(1000);
+    let slope = ratio::<T>(1292, 100);
+    let (offset, scale) = (ratio::<T>(11, 200), ratio::<T>(211, 200));
+    let gamma = ratio::<T>(5, 12);
+    map3(c, |c| {
+        if c <= threshold {
+            c * slope
+        } else {
+            scale * c.powf(gamma) - offset
+        }
+    })
+}
+
+/// Convert hue, saturation and value to RGB.
+///
+/// The hue is a fraction of a full turn, so `0`, `1/3` and `2/3` are red, green and
+/// blue; it wraps around outside `[0, 1)`.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::color::hsv_to_rgb};
+///
+/// assert_eq!(hsv_to_rgb(Vec3::new([[0.0, 1.0, 1.0]])), Vec3::new([[1.0, 0.0, 0.0]]));
+/// assert_eq!(hsv_to_rgb(Vec3::new([[0.5, 0.5, 1.0]])), Vec3::new([[0.5, 1.0, 1.0]]));
+/// ```
+#[inline]
+pub fn hsv_to_rgb<T: Float>(hsv: Vec3<T>) -> Vec3<T> {
+    let (h, s, v) = (hsv.x(), hsv.y(), hsv.z());
+    let (one, six) = (T::unit_one(), integer::<T>(6));
+    let four = integer::<T>(4);
+    let h = (h - h.floor()) * six;
+    // Each channel falls off linearly from its own sector, see
+    // https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_RGB_alternative.
+    let channel = |n: u32| {
+        let k = integer::<T>(n) + h;
+        let k = k - six * (k / six).floor();
+        v - v * s * k.min(four - k).min(one).max(T::zero())
+    };
+    Mat {
+        data: [[channel(5), channel(3), channel(1)]],
+    }
+}
+
+/// Convert RGB to hue, saturation and value, the inverse of [`hsv_to_rgb`].
+///
+/// Grays have zero hue and saturation, and black has zero saturation.
+///
+/// # Example
+/// ```
+/// use mats::{Vec3, graphics::color::rgb_to_hsv};
+///
+/// assert_eq!(rgb_to_hsv(Vec3::new([[0.0, 0.0, 1.0]])).x(), 4.0 / 6.0);
+/// assert_eq!(rgb_to_hsv(Vec3::new([[0.5, 0.5, 0.5]])), Vec3::new([[0.0, 0.0, 0.5]]));
+/// ```
+#[inline]
+pub fn rgb_to_hsv<T: Float>(rgb: Vec3<T>) -> Vec3<T> {
+    let (r, g, b) = (rgb.x(), rgb.y(), rgb.z());
+    let o = T::zero();
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    let s = if max == o { o } else { delta / max };
+    let h = if delta == o {
+        o
+    } else if max == r {
+        let h = (g - b) / delta;
+        if h < o { h + integer(6) } else { h }
+    } else if max == g {
+        (b - r) / delta + integer(2)
+    } else {
+        (r - g) / delta + integer(4)
+    };
+    Mat {
+        data: [[h / integer(6), s, max]],
+    }
+}
+
+/// [`srgb_to_linear`] on the color channels, passing alpha through.
+#[inline]
+pub fn srgba_to_linear<T: Float>(c: Vec4<T>) -> Vec4<T> {
+    with_alpha(c, srgb_to_linear)
+}
+
+/// [`linear_to_srgb`] on the color channels, passing alpha through.
+#[inline]
+pub fn linear_to_srgba<T: Float>(c: Vec4<T>) -> Vec4<T> {
+    with_alpha(c, linear_to_srgb)
+}
+
+/// [`hsv_to_rgb`] on the color channels, passing alpha through.
+#[inline]
+pub fn hsva_to_rgba<T: Float>(c: Vec4<T>) -> Vec4<T> {
+    with_alpha(c, hsv_to_rgb)
+}
+
+/// [`rgb_to_hsv`] on the color channels, passing alpha through.
+///
+/// # Example
+/// ```
+/// use mats::{Vec4, graphics::color::rgba_to_hsva};
+///
+/// let hsva = rgba_to_hsva(Vec4::new([[0.0, 1.0, 0.0, 0.25]]));
+///
+/// assert_eq!(hsva, Vec4::new([[1.0 / 3.0, 1.0, 1.0, 0.25]]));
+/// ```
+#[inline]
+pub fn rgba_to_hsva<T: Float>(c: Vec4<T>) -> Vec4<T> {
+    with_alpha(c, rgb_to_hsv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> impl Iterator<Item = Vec3<f64>> {
+        let steps = 11;
+        let at = move |i: usize| i as f64 / (steps - 1) as f64;
+        (0..steps * steps * steps).map(move |i| {
+            Vec3::new([[at(i % steps), at(i / steps % steps), at(i / steps / steps)]])
+        })
+    }
+
+    fn max_error(a: Vec3<f64>, b: Vec3<f64>) -> f64 {
+        (a - b).raw_data()[0]
+            .iter()
+            .fold(0.0, |m, e| m.max(e.abs()))
+    }
+
+    #[test]
+    fn test_constants_match_literals() {
+        assert_eq!(ratio::<f64>(809, 20000), 0.04045);
+        assert_eq!(
+            ratio::<f64>(31308, 10_000) / integer::<f64>(1000),
+            0.0031308
+        );
+        assert_eq!(
+            ratio::<f32>(31308, 10_000) / integer::<f32>(1000),
+            0.0031308
+        );
+        assert_eq!(ratio::<f32>(1292, 100), 12.92);
+        assert_eq!(ratio::<f32>(211, 200), 1.055);
+        assert_eq!(integer::<f32>(0), 0.0);
+    }
+
+    #[test]
+    fn test_srgb_round_trip() {
+        for c in grid() {
+            assert!(
+                max_error(linear_to_srgb(srgb_to_linear(c)), c) < 1e-6,
+                "{c:?}"
+            );
+            assert!(
+                max_error(srgb_to_linear(linear_to_srgb(c)), c) < 1e-6,
+                "{c:?}"
+            );
+        }
+
+        // Both pieces meet at the breakpoints.
+        let below = Vec3::new([[0.04045, 0.0031308, 0.0]]);
+        let above = Vec3::new([[0.04045 + 1e-12, 0.0031308 + 1e-12, 0.0]]);
+        let (l0, l1) = (srgb_to_linear(below), srgb_to_linear(above));
+        assert_eq!(l0.x(), 0.04045 / 12.92);
+        assert!((l1.x() - l0.x()).abs() < 1e-7);
+        let (s0, s1) = (linear_to_srgb(below), linear_to_srgb(above));
+        assert_eq!(s0.y(), 0.0031308 * 12.92);
+        assert!((s1.y() - s0.y()).abs() < 1e-7);
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn test_srgb_f16() {
+        use half::f16;
+
+        let (dark, mid) = (f16::from_f64(0.002), f16::from_f64(0.5));
+        let srgb = linear_to_srgb(Vec3::new([[f16::ZERO, dark, mid]]));
+        assert_eq!(srgb.x(), f16::ZERO);
+        assert_eq!(srgb.y(), dark * f16::from_f64(12.92));
+        assert!((srgb.z().to_f64() - 0.7354).abs() < 1e-3);
+        let back = srgb_to_linear(srgb);
+        assert_eq!(back.x(), f16::ZERO);
+        assert!((back.y().to_f64() - 0.002).abs() < 1e-5);
+        assert!((back.z().to_f64() - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_hsv() {
+        let rgb = |r, g, b| Vec3::new([[r, g, b]]);
+        let primaries = [
+            (rgb(1.0, 0.0, 0.0), 0.0),
+            (rgb(0.0, 1.0, 0.0), 1.0 / 3.0),
+            (rgb(0.0, 0.0, 1.0), 2.0 / 3.0),
+        ];
+        for (c, hue) in primaries {
+            assert_eq!(rgb_to_hsv(c), Vec3::new([[hue, 1.0, 1.0]]));
+            assert!(max_error(hsv_to_rgb(rgb_to_hsv(c)), c) < 1e-15);
+        }
+        assert_eq!(hsv_to_rgb(Vec3::new([[1.0, 1.0, 1.0]])), rgb(1.0, 0.0, 0.0));
+
+        for c in grid() {
+            assert!(max_error(hsv_to_rgb(rgb_to_hsv(c)), c) < 1e-6, "{c:?}");
+        }
+
+        let alpha = srgba_to_linear(linear_to_srgba(hsva_to_rgba(Vec4::new([[
+            0.1, 0.2, 0.3, 0.4,
+        ]]))));
+        assert_eq!(alpha.w(), 0.4);
+    }
+}
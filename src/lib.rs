@@ -1,5 +1,19 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("mats needs either the `std` or the `libm` feature for floating-point math");
+
+use core::borrow::{Borrow, BorrowMut};
+
 /// A column-major matrix with `ROWS` rows and `COLS` columns, and elements of type `T`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// The matrix has the same layout as `[[T; ROWS]; COLS]`: its `ROWS * COLS` elements
+/// are stored contiguously, column after column, with no padding.
+///
+/// Matrices are ordered lexicographically in that same column-major order, so
+/// vectors compare element by element and can be used as `BTreeMap` keys.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
 pub struct Mat<const ROWS: usize, const COLS: usize, T = f32> {
     data: [[T; ROWS]; COLS],
 }
@@ -18,6 +32,25 @@ impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
         Self { data }
     }
 
+    /// Create a new matrix with every element set to `f(row, col)`.
+    ///
+    /// `f` is called in column-major order, like [`fill_with`](Self::fill_with).
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 3, usize>::from_fn(|i, j| 10 * i + j);
+    ///
+    /// assert_eq!(m.to_rows_array_2d(), [[0, 1, 2], [10, 11, 12]]);
+    /// ```
+    #[inline]
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Self {
+            data: core::array::from_fn(|j| core::array::from_fn(|i| f(i, j))),
+        }
+    }
+
     /// Return a reference to the raw data of the matrix.
     ///
     /// # Example
@@ -32,6 +65,272 @@ impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
     pub const fn raw_data(&self) -> &[[T; ROWS]; COLS] {
         &self.data
     }
+
+    /// Return a mutable reference to the raw data of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut m = Mat::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// m.raw_data_mut()[1][0] = 5.0;
+    /// assert_eq!(m[(0, 1)], 5.0);
+    /// ```
+    #[inline]
+    pub const fn raw_data_mut(&mut self) -> &mut [[T; ROWS]; COLS] {
+        &mut self.data
+    }
+
+    /// Create a new matrix from an array of columns.
+    ///
+    /// This is the storage order of `Mat`, so it is the same as `Mat::new`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 3, i32>::from_cols_array_2d([[1, 4], [2, 5], [3, 6]]);
+    ///
+    /// assert_eq!(m[(0, 2)], 3);
+    /// assert_eq!(m[(1, 0)], 4);
+    /// ```
+    #[inline]
+    pub const fn from_cols_array_2d(cols: [[T; ROWS]; COLS]) -> Self {
+        Self { data: cols }
+    }
+
+    /// Create a new matrix from an array of rows.
+    ///
+    /// The elements are moved, so `T` does not need to be `Copy`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(m[(0, 2)], 3);
+    /// assert_eq!(m[(1, 0)], 4);
+    /// ```
+    #[inline]
+    pub fn from_rows_array_2d(rows: [[T; COLS]; ROWS]) -> Self {
+        // Column `j` takes the next element of every row, in row order.
+        let mut rows = rows.map(IntoIterator::into_iter);
+        Self {
+            data: core::array::from_fn(|_| {
+                core::array::from_fn(|i| rows[i].next().expect("row has COLS elements"))
+            }),
+        }
+    }
+
+    /// Convert the matrix into an array of rows.
+    ///
+    /// The elements are moved, so `T` does not need to be `Copy`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 2, String>::from_cols_array_2d([["a".into(), "c".into()], ["b".into(), "d".into()]]);
+    ///
+    /// assert_eq!(m.into_rows_array_2d(), [["a", "b"], ["c", "d"]]);
+    /// ```
+    #[inline]
+    pub fn into_rows_array_2d(self) -> [[T; COLS]; ROWS] {
+        let mut cols = self.data.map(IntoIterator::into_iter);
+        core::array::from_fn(|_| {
+            core::array::from_fn(|j| cols[j].next().expect("column has ROWS elements"))
+        })
+    }
+
+    /// Create a new matrix from an array of column vectors.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat, Vec2};
+    ///
+    /// let m = Mat::<2, 3, i32>::from_cols([
+    ///     Vec2::new([[1, 4]]),
+    ///     Vec2::new([[2, 5]]),
+    ///     Vec2::new([[3, 6]]),
+    /// ]);
+    ///
+    /// assert_eq!(m, Mat::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    #[inline]
+    pub fn from_cols(cols: [Vec<ROWS, T>; COLS]) -> Self {
+        Self {
+            data: cols.map(Vec::to_array),
+        }
+    }
+
+    /// Create a new matrix from an array of row vectors.
+    ///
+    /// The rows are given as column vectors, so this is the transpose of
+    /// [`from_cols`](Self::from_cols).
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat, Vec3};
+    ///
+    /// let m = Mat::<2, 3, i32>::from_rows([
+    ///     Vec3::new([[1, 2, 3]]),
+    ///     Vec3::new([[4, 5, 6]]),
+    /// ]);
+    ///
+    /// assert_eq!(m, Mat::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    #[inline]
+    pub fn from_rows(rows: [Vec<COLS, T>; ROWS]) -> Self {
+        Self::from_rows_array_2d(rows.map(Vec::to_array))
+    }
+
+    /// Create a new matrix from an iterator of column vectors.
+    ///
+    /// Returns `None` unless the iterator yields exactly `COLS` columns; use
+    /// `Iterator::take` to build a matrix from the front of a longer iterator.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat, Vec3};
+    ///
+    /// let points = (0..4).map(|i| Vec3::new([[i, i * i, 1]]));
+    /// let m = Mat::<3, 4, i32>::try_from_col_iter(points).unwrap();
+    ///
+    /// assert_eq!(m.col_ref(3), &[3, 9, 1]);
+    /// assert!(Mat::<3, 4, i32>::try_from_col_iter([Vec3::new([[0, 0, 0]])]).is_none());
+    /// ```
+    #[inline]
+    pub fn try_from_col_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Vec<ROWS, T>>,
+    {
+        let mut iter = iter.into_iter();
+        let cols: [Option<[T; ROWS]>; COLS] =
+            core::array::from_fn(|_| iter.next().map(Vec::to_array));
+        if iter.next().is_some() || cols.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(Self {
+            data: cols.map(|col| col.expect("checked above")),
+        })
+    }
+
+    /// Convert the matrix into an array of column vectors.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat, Vec2};
+    ///
+    /// let m = Mat::<2, 2, i32>::from_rows_array_2d([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(m.to_cols(), [Vec2::new([[1, 3]]), Vec2::new([[2, 4]])]);
+    /// ```
+    #[inline]
+    pub fn to_cols(self) -> [Vec<ROWS, T>; COLS] {
+        self.data.map(|col| Vec::new([col]))
+    }
+
+    /// Return a reference to the `j`-th column of the matrix.
+    ///
+    /// This is free, because the matrix is stored column by column.
+    ///
+    /// # Panics
+    /// Panics if `j >= COLS`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(m.col_ref(1), &[2, 5]);
+    /// ```
+    #[inline]
+    pub const fn col_ref(&self, j: usize) -> &[T; ROWS] {
+        &self.data[j]
+    }
+
+    /// Return a mutable reference to the `j`-th column of the matrix.
+    ///
+    /// # Panics
+    /// Panics if `j >= COLS`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+    /// m.col_mut(2)[1] = 0;
+    ///
+    /// assert_eq!(m[(1, 2)], 0);
+    /// ```
+    #[inline]
+    pub fn col_mut(&mut self, j: usize) -> &mut [T; ROWS] {
+        &mut self.data[j]
+    }
+
+    /// Swap the `i`-th and `j`-th rows of the matrix.
+    ///
+    /// # Panics
+    /// Panics if `i >= ROWS` or `j >= ROWS`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+    /// m.swap_rows(0, 1);
+    ///
+    /// assert_eq!(m.to_rows_array_2d(), [[4, 5, 6], [1, 2, 3]]);
+    /// ```
+    #[inline]
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        for col in self.data.iter_mut() {
+            col.swap(i, j);
+        }
+    }
+
+    /// Swap the `i`-th and `j`-th columns of the matrix.
+    ///
+    /// # Panics
+    /// Panics if `i >= COLS` or `j >= COLS`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+    /// m.swap_cols(0, 2);
+    ///
+    /// assert_eq!(m.to_rows_array_2d(), [[3, 2, 1], [6, 5, 4]]);
+    /// ```
+    #[inline]
+    pub fn swap_cols(&mut self, i: usize, j: usize) {
+        self.data.swap(i, j);
+    }
+
+    /// Set every element to `f(row, col)`, in place.
+    ///
+    /// `f` is called in column-major order, the storage order of `Mat`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut m = Mat::<2, 3, usize>::default();
+    /// m.fill_with(|i, j| 10 * i + j);
+    ///
+    /// assert_eq!(m.to_rows_array_2d(), [[0, 1, 2], [10, 11, 12]]);
+    /// ```
+    #[inline]
+    pub fn fill_with(&mut self, mut f: impl FnMut(usize, usize) -> T) {
+        for (j, col) in self.data.iter_mut().enumerate() {
+            for (i, x) in col.iter_mut().enumerate() {
+                *x = f(i, j);
+            }
+        }
+    }
 }
 
 impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
@@ -74,8 +373,174 @@ where
             }
         }
     }
+
+    /// Overwrite the matrix with the elements of `slice`, in column-major order.
+    ///
+    /// # Panics
+    /// Panics if `slice.len() != ROWS * COLS`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut m = Mat::<2, 3, i32>::init(0);
+    /// m.copy_from_slice(&[1, 4, 2, 5, 3, 6]);
+    ///
+    /// assert_eq!(m.to_rows_array_2d(), [[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    #[inline]
+    pub fn copy_from_slice(&mut self, slice: &[T]) {
+        assert_eq!(
+            slice.len(),
+            ROWS * COLS,
+            "slice length does not match the number of matrix elements"
+        );
+        for (col, chunk) in self.data.iter_mut().zip(slice.chunks_exact(ROWS.max(1))) {
+            col.copy_from_slice(chunk);
+        }
+    }
+
+    /// Convert the matrix into an array of columns.
+    ///
+    /// Use `<[[T; ROWS]; COLS]>::from` to move the columns out of a matrix whose
+    /// elements are not `Copy`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(m.to_cols_array_2d(), [[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    #[inline]
+    pub fn to_cols_array_2d(&self) -> [[T; ROWS]; COLS] {
+        self.data
+    }
+
+    /// Convert the matrix into an array of rows.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 3, i32>::from_cols_array_2d([[1, 4], [2, 5], [3, 6]]);
+    ///
+    /// assert_eq!(m.to_rows_array_2d(), [[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    #[inline]
+    pub fn to_rows_array_2d(&self) -> [[T; COLS]; ROWS] {
+        core::array::from_fn(|i| core::array::from_fn(|j| self.data[j][i]))
+    }
+
+    /// Return the matrix whose `k`-th row is the `perm[k]`-th row of this one.
+    ///
+    /// This equals `Mat::permutation(perm) * self`, without the multiplication.
+    ///
+    /// # Panics
+    /// Panics if any index in `perm` is `>= ROWS`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<3, 2, i32>::from_rows_array_2d([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// assert_eq!(m.permute_rows(&[2, 0, 1]).to_rows_array_2d(), [[5, 6], [1, 2], [3, 4]]);
+    /// ```
+    #[inline]
+    pub fn permute_rows(&self, perm: &[usize; ROWS]) -> Self {
+        Self {
+            data: core::array::from_fn(|j| core::array::from_fn(|k| self.data[j][perm[k]])),
+        }
+    }
 }
 
+/// Flat array conversions, for the sizes whose element count can be named.
+macro_rules! impl_flat_arrays {
+    ($(($rows:literal, $cols:literal)),*) => {
+        $(
+            impl<T: Copy> Mat<$rows, $cols, T> {
+                /// Convert the matrix into a flat array in column-major order.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Mat;
+                ///
+                /// let m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+                ///
+                /// assert_eq!(m.to_cols_array(), [1, 4, 2, 5, 3, 6]);
+                /// ```
+                #[inline]
+                pub fn to_cols_array(&self) -> [T; $rows * $cols] {
+                    core::array::from_fn(|k| self.data[k / $rows][k % $rows])
+                }
+
+                /// Convert the matrix into a flat array in row-major order.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Mat;
+                ///
+                /// let m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+                ///
+                /// assert_eq!(m.to_rows_array(), [1, 2, 3, 4, 5, 6]);
+                /// ```
+                #[inline]
+                pub fn to_rows_array(&self) -> [T; $rows * $cols] {
+                    core::array::from_fn(|k| self.data[k % $cols][k / $cols])
+                }
+
+                /// Create a new matrix from a flat array in column-major order.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Mat;
+                ///
+                /// let m = Mat::<2, 3, i32>::from_cols_array(&[1, 4, 2, 5, 3, 6]);
+                ///
+                /// assert_eq!(m.to_rows_array_2d(), [[1, 2, 3], [4, 5, 6]]);
+                /// ```
+                #[inline]
+                pub fn from_cols_array(array: &[T; $rows * $cols]) -> Self {
+                    Self {
+                        data: core::array::from_fn(|j| core::array::from_fn(|i| array[j * $rows + i])),
+                    }
+                }
+
+                /// Create a new matrix from a flat array in row-major order.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Mat;
+                ///
+                /// let m = Mat::<2, 3, i32>::from_rows_array(&[1, 2, 3, 4, 5, 6]);
+                ///
+                /// assert_eq!(m.to_cols_array_2d(), [[1, 4], [2, 5], [3, 6]]);
+                /// ```
+                #[inline]
+                pub fn from_rows_array(array: &[T; $rows * $cols]) -> Self {
+                    Self {
+                        data: core::array::from_fn(|j| core::array::from_fn(|i| array[i * $cols + j])),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_flat_arrays!(
+    (2, 2),
+    (2, 3),
+    (2, 4),
+    (3, 2),
+    (3, 3),
+    (3, 4),
+    (4, 2),
+    (4, 3),
+    (4, 4)
+);
+
 // Implement trait Default for Mat
 impl<T, const ROWS: usize, const COLS: usize> Default for Mat<ROWS, COLS, T>
 where
@@ -99,6 +564,92 @@ where
     }
 }
 
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    /// Writes the elements row by row with `element`, which receives the formatter
+    /// so its flags apply to every element.
+    fn fmt_rows(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        element: fn(&T, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        f.write_str("[")?;
+        for i in 0..ROWS {
+            if f.alternate() {
+                f.write_str("\n    ")?;
+            } else if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str("[")?;
+            for j in 0..COLS {
+                if j > 0 {
+                    f.write_str(", ")?;
+                }
+                element(&self.data[j][i], f)?;
+            }
+            f.write_str("]")?;
+            if f.alternate() {
+                f.write_str(",")?;
+            }
+        }
+        if f.alternate() && ROWS > 0 {
+            f.write_str("\n")?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::fmt::Debug for Mat<ROWS, COLS, T>
+where
+    T: core::fmt::Debug,
+{
+    /// Format the matrix with its type, row by row.
+    ///
+    /// The rows are printed as the matrix is written on paper, even though the
+    /// storage is column-major (see [`raw_data`](Mat::raw_data) for the columns).
+    /// Width, precision and the alternate flag work as for `Display`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(format!("{m:?}"), "Mat<2, 3, i32> [[1, 2, 3], [4, 5, 6]]");
+    /// assert_eq!(
+    ///     format!("{m:#?}"),
+    ///     "Mat<2, 3, i32> [\n    [1, 2, 3],\n    [4, 5, 6],\n]"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Mat<{ROWS}, {COLS}, {}> ", core::any::type_name::<T>())?;
+        self.fmt_rows(f, core::fmt::Debug::fmt)
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::fmt::Display for Mat<ROWS, COLS, T>
+where
+    T: core::fmt::Display,
+{
+    /// Format the matrix row by row, as it is written on paper.
+    ///
+    /// Width, precision and sign flags are applied to every element. The alternate
+    /// flag (`{:#}`) puts each row on its own line.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 2, f32>::from_rows_array_2d([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(format!("{m}"), "[[1, 2], [3, 4]]");
+    /// assert_eq!(format!("{m:.1}"), "[[1.0, 2.0], [3.0, 4.0]]");
+    /// assert_eq!(format!("{m:#4}"), "[\n    [   1,    2],\n    [   3,    4],\n]");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_rows(f, core::fmt::Display::fmt)
+    }
+}
+
 impl<T, const ROWS: usize, const COLS: usize> From<[[T; ROWS]; COLS]> for Mat<ROWS, COLS, T> {
     /// Convert a 2D array into a matrix.
     ///
@@ -133,20 +684,191 @@ impl<T, const SIZE: usize> From<[T; SIZE]> for Mat<SIZE, 1, T> {
     }
 }
 
+impl<T, const ROWS: usize, const COLS: usize> From<Mat<ROWS, COLS, T>> for [[T; ROWS]; COLS] {
+    /// Convert a matrix into a 2D array of columns.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let arr: [[i32; 2]; 2] = Mat::new([[1, 2], [3, 4]]).into();
+    ///
+    /// assert_eq!(arr, [[1, 2], [3, 4]]);
+    /// ```
+    #[inline]
+    fn from(value: Mat<ROWS, COLS, T>) -> Self {
+        value.data
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> AsRef<[[T; ROWS]; COLS]> for Mat<ROWS, COLS, T> {
+    /// Borrow the matrix as a 2D array of columns, the same as `raw_data`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat4;
+    ///
+    /// fn upload(data: &[[f32; 4]; 4]) -> f32 {
+    ///     data[3][0]
+    /// }
+    ///
+    /// let mut m = Mat4::I();
+    /// m[(0, 3)] = 2.0;
+    /// assert_eq!(upload(m.as_ref()), 2.0);
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &[[T; ROWS]; COLS] {
+        &self.data
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> AsMut<[[T; ROWS]; COLS]> for Mat<ROWS, COLS, T> {
+    /// Borrow the matrix mutably as a 2D array of columns, the same as `raw_data_mut`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut m = Mat::<2, 2, i32>::new([[1, 2], [3, 4]]);
+    /// let data: &mut [[i32; 2]; 2] = m.as_mut();
+    /// data[0][1] = 7;
+    ///
+    /// assert_eq!(m[(1, 0)], 7);
+    /// ```
+    #[inline]
+    fn as_mut(&mut self) -> &mut [[T; ROWS]; COLS] {
+        &mut self.data
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> AsRef<[T]> for Mat<ROWS, COLS, T> {
+    /// Borrow the matrix as a flat slice of its `ROWS * COLS` elements in column-major order.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 3, i32>::from_rows_array_2d([[1, 2, 3], [4, 5, 6]]);
+    /// let flat: &[i32] = m.as_ref();
+    ///
+    /// assert_eq!(flat, &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> AsMut<[T]> for Mat<ROWS, COLS, T> {
+    /// Borrow the matrix mutably as a flat slice of its elements in column-major order.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut m = Mat::<2, 2, i32>::new([[1, 2], [3, 4]]);
+    /// let flat: &mut [i32] = m.as_mut();
+    /// flat[2] = 9;
+    ///
+    /// assert_eq!(m[(0, 1)], 9);
+    /// ```
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut()
+    }
+}
+
+// Hashing, equality and ordering of `Mat` are those of its data, so borrowing as the
+// 2D array is sound. The flat slice hashes differently and is not a valid `Borrow`.
+impl<T, const ROWS: usize, const COLS: usize> Borrow<[[T; ROWS]; COLS]> for Mat<ROWS, COLS, T> {
+    #[inline]
+    fn borrow(&self) -> &[[T; ROWS]; COLS] {
+        &self.data
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> BorrowMut<[[T; ROWS]; COLS]> for Mat<ROWS, COLS, T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [[T; ROWS]; COLS] {
+        &mut self.data
+    }
+}
+
 mod base;
+mod error;
+mod fit;
 mod math;
 mod ops;
+#[cfg(feature = "rand")]
+mod random;
 mod traits;
 mod types;
 mod utils;
 
+pub use error::Error;
+pub use fit::*;
 pub use traits::*;
 pub use types::*;
 pub use utils::*;
 
+/// Module `gpu` describes how vectors and matrices are laid out as vertex attributes.
+pub mod gpu;
+
 /// `#[cfg(feature = "graphics")]`: Module `graphics` provides a range
 /// of facilities or tools for computer graphics.
 ///
 /// When the `graphics` feature is enabled, this module will be available.
 #[cfg(feature = "graphics")]
 pub mod graphics;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_and_row_vectors() {
+        let mut m = Mat::<3, 4, i32>::default();
+        m.fill_with(|i, j| (i * 4 + j) as i32);
+        assert_eq!(Mat::from_cols(m.to_cols()), m);
+        assert_eq!(Mat::<4, 3, i32>::from_rows(m.to_cols()), m.transpose());
+        assert_eq!(Mat::try_from_col_iter(m.to_cols()), Some(m));
+        assert_eq!(
+            Mat::<3, 4, i32>::try_from_col_iter(m.to_cols().into_iter().take(3)),
+            None
+        );
+        assert_eq!(
+            Mat::<3, 4, i32>::try_from_col_iter(m.to_cols().into_iter().cycle().take(5)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_array_views() {
+        let mut m = Mat::<3, 2, i32>::new([[1, 2, 3], [4, 5, 6]]);
+
+        let flat: &[i32] = m.as_ref();
+        assert_eq!(flat.len(), 3 * 2);
+
+        let cols: &mut [[i32; 3]; 2] = m.as_mut();
+        cols[1][2] = 60;
+        assert_eq!(m[(2, 1)], 60);
+
+        let flat: &mut [i32] = m.as_mut();
+        flat[0] = 10;
+        assert_eq!(m[(0, 0)], 10);
+
+        #[cfg(feature = "std")]
+        {
+            let set = std::collections::HashSet::from([m]);
+            assert!(set.contains(&[[10, 2, 3], [4, 5, 60]]));
+        }
+        assert_eq!(<[[i32; 3]; 2]>::from(m), *m.raw_data());
+    }
+
+    #[test]
+    #[should_panic(expected = "slice length")]
+    fn test_copy_from_slice_length_mismatch() {
+        let mut m = Mat::<2, 2, i32>::init(0);
+        m.copy_from_slice(&[1, 2, 3]);
+    }
+}
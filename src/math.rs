@@ -1,14 +1,27 @@
-use crate::{Mat, Zero, types::Vec3};
+use crate::{
+    Angle, Conjugate, Euclid, Float, Mat, Rad, UnitOne, Zero,
+    types::{Mat2, Mat3, Mat4, RowVec, Vec, Vec2, Vec3},
+};
 
 impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
 where
-    T: Copy + Zero + std::ops::AddAssign + std::ops::Mul<Output = T>,
+    T: Copy + Zero + core::ops::Add<Output = T> + core::ops::Mul<Output = T>,
 {
     /// Computes the dot product of two matrices.
     ///
     /// The result is a new matrix with `ROWS` rows and `OTHER_COLS` columns, where
     /// `OTHER_COLS` is the number of columns in the second matrix.
     ///
+    /// # Note
+    /// This is the **matrix product**. For two column vectors `a.dot(&b.T())` is their
+    /// outer product, an `N x N` matrix; use [`inner_product`](Self::inner_product)
+    /// for the scalar dot product.
+    ///
+    /// Each element is summed left to right with separate multiplies and adds.
+    /// Rust never contracts these into fused multiply-adds, so the result is
+    /// bit-identical on every target; see [`dot_fma`](Self::dot_fma) for the fused
+    /// variant.
+    ///
     /// # Example
     ///
     /// ```
@@ -20,27 +33,29 @@ where
     ///
     /// assert_eq!(c, Mat::new([[-25, -21, 73], [-12, 24, 18]]));
     /// ```
+    #[inline]
     pub fn dot<const OTHER_COLS: usize>(
         &self,
         other: &Mat<COLS, OTHER_COLS, T>,
     ) -> Mat<ROWS, OTHER_COLS, T> {
-        let mut result = Mat {
-            data: [[T::zero(); ROWS]; OTHER_COLS],
-        };
-        for i in 0..ROWS {
-            for j in 0..OTHER_COLS {
-                for k in 0..COLS {
-                    result.data[j][i] += self.data[k][i] * other.data[j][k];
-                }
-            }
+        // Each element is written once as a plain sum of products, so for
+        // small fixed sizes (Mat2, Mat3, Mat4) the loops unroll into
+        // straight-line code instead of zero-filling and accumulating.
+        Mat {
+            data: core::array::from_fn(|j| {
+                core::array::from_fn(|i| {
+                    let mut products = (0..COLS).map(|k| self.data[k][i] * other.data[j][k]);
+                    let first = products.next().unwrap_or_else(T::zero);
+                    products.fold(first, |sum, product| sum + product)
+                })
+            }),
         }
-        result
     }
 }
 
 impl<T> crate::types::Vec3<T>
 where
-    T: Copy + std::ops::Sub<Output = T> + std::ops::Mul<Output = T>,
+    T: Copy + core::ops::Sub<Output = T> + core::ops::Mul<Output = T>,
 {
     /// Computes the cross product of two vectors.
     ///
@@ -65,6 +80,168 @@ where
     }
 }
 
+impl<T> crate::types::Vec3<T>
+where
+    T: Copy + Zero + core::ops::Neg<Output = T>,
+{
+    /// Returns the skew-symmetric cross product matrix `[w]×` of this vector `w`,
+    /// so that `w.cross_matrix() * v == w.cross(&v)`.
+    ///
+    /// In rows, the matrix is `[[0, -z, y], [z, 0, -x], [-y, x, 0]]`; since `Mat` is
+    /// column-major, its raw data (the columns) is `[[0, z, -y], [-z, 0, x], [y, -x, 0]]`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let w = Vec3::new([[1, 2, 3]]);
+    /// let v = Vec3::new([[4, 5, 6]]);
+    ///
+    /// assert_eq!(w.cross_matrix().to_rows_array_2d(), [[0, -3, 2], [3, 0, -1], [-2, 1, 0]]);
+    /// assert_eq!(w.cross_matrix() * v, w.cross(&v));
+    /// ```
+    #[inline]
+    pub fn cross_matrix(&self) -> crate::types::Mat3<T> {
+        let (x, y, z) = (self.x(), self.y(), self.z());
+        let o = T::zero();
+        Mat {
+            data: [[o, z, -y], [-z, o, x], [y, -x, o]],
+        }
+    }
+}
+
+impl<T, const N: usize> Vec<N, T>
+where
+    T: Copy + Zero + core::ops::Add<Output = T> + core::ops::Mul<Output = T>,
+{
+    /// Computes the inner (scalar) product of two column vectors.
+    ///
+    /// Unlike [`dot`](Self::dot), which is the matrix product, this needs no
+    /// transpose and returns a scalar. Like `dot` it never uses fused
+    /// multiply-adds, see [`inner_product_fma`](Self::inner_product_fma).
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let a = Vec3::new([[1, 2, 3]]);
+    /// let b = Vec3::new([[4, -5, 6]]);
+    ///
+    /// assert_eq!(a.inner_product(&b), 12);
+    /// assert_eq!(a.T().dot(&b), 12);
+    /// ```
+    #[inline]
+    pub fn inner_product(&self, other: &Self) -> T {
+        self.data[0]
+            .iter()
+            .zip(other.data[0].iter())
+            .fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+    }
+}
+
+impl<T: Float, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    /// Computes the matrix product like [`dot`](Self::dot), but accumulates each
+    /// element with [`Float::mul_add`].
+    ///
+    /// Every step rounds once, so the result is usually more accurate than `dot`
+    /// and is still the same on every target, as long as the hardware or `libm`
+    /// provides a correctly rounded `fma`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::<1, 2, f64>::new([[-1.0], [0.1]]);
+    /// let b = Mat::<2, 1, f64>::new([[1.0, 10.0]]);
+    ///
+    /// assert_eq!(a.dot(&b)[(0, 0)], 0.0);
+    /// assert_eq!(a.dot_fma(&b)[(0, 0)], 2f64.powi(-54));
+    /// ```
+    #[inline]
+    pub fn dot_fma<const OTHER_COLS: usize>(
+        &self,
+        other: &Mat<COLS, OTHER_COLS, T>,
+    ) -> Mat<ROWS, OTHER_COLS, T> {
+        Mat {
+            data: core::array::from_fn(|j| {
+                core::array::from_fn(|i| {
+                    let mut pairs = (0..COLS).map(|k| (self.data[k][i], other.data[j][k]));
+                    let first = pairs.next().map_or_else(T::zero, |(a, b)| a * b);
+                    pairs.fold(first, |sum, (a, b)| a.mul_add(b, sum))
+                })
+            }),
+        }
+    }
+
+    /// Computes `self * a + b` element-wise with [`Float::mul_add`].
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// let x = Vec2::new([[1.0, 2.0]]);
+    /// let y = Vec2::new([[0.5, -1.0]]);
+    ///
+    /// assert_eq!(x.mul_add(3.0, &y), Vec2::new([[3.5, 5.0]]));
+    /// ```
+    #[inline]
+    pub fn mul_add(&self, a: T, b: &Self) -> Self {
+        Mat {
+            data: core::array::from_fn(|j| {
+                core::array::from_fn(|i| self.data[j][i].mul_add(a, b.data[j][i]))
+            }),
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Vec<N, T> {
+    /// Computes the inner product like [`inner_product`](Self::inner_product), but
+    /// accumulates with [`Float::mul_add`].
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// let a = Vec2::new([[-1.0, 0.1]]);
+    /// let b = Vec2::new([[1.0, 10.0]]);
+    ///
+    /// assert_eq!(a.inner_product(&b), 0.0);
+    /// assert_eq!(a.inner_product_fma(&b), 2f64.powi(-54));
+    /// ```
+    #[inline]
+    pub fn inner_product_fma(&self, other: &Self) -> T {
+        self.data[0]
+            .iter()
+            .zip(other.data[0].iter())
+            .fold(T::zero(), |acc, (&a, &b)| a.mul_add(b, acc))
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Copy + core::ops::Mul<Output = T>,
+{
+    /// Returns the outer product `a * bᵀ` of two column vectors.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat, Vec2, Vec3};
+    ///
+    /// let a = Vec3::new([[1, 2, 3]]);
+    /// let b = Vec2::new([[4, 5]]);
+    /// let m = Mat::from_outer_product(&a, &b);
+    ///
+    /// assert_eq!(m.to_rows_array_2d(), [[4, 5], [8, 10], [12, 15]]);
+    /// assert_eq!(m, a.dot(&b.T()));
+    /// ```
+    #[inline]
+    pub fn from_outer_product(a: &Mat<ROWS, 1, T>, b: &Mat<COLS, 1, T>) -> Self {
+        Self {
+            data: core::array::from_fn(|j| core::array::from_fn(|i| a.data[0][i] * b.data[0][j])),
+        }
+    }
+}
+
 impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
 where
     T: Copy,
@@ -81,14 +258,9 @@ where
     /// assert_eq!(b, Mat::new([[1, -2], [3, 0], [-4, 5]]));
     /// ```
     pub fn transpose(&self) -> Mat<COLS, ROWS, T> {
-        let mut result: Mat<COLS, ROWS, T> =
-            unsafe { std::mem::MaybeUninit::uninit().assume_init() };
-        for i in 0..ROWS {
-            for j in 0..COLS {
-                result.data[i][j] = self.data[j][i];
-            }
+        Mat {
+            data: core::array::from_fn(|i| core::array::from_fn(|j| self.data[j][i])),
         }
-        result
     }
 
     /// Computes the transpose of the matrix.
@@ -110,3 +282,2164 @@ where
         self.transpose()
     }
 }
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Euclid,
+{
+    /// Computes the Euclidean division of every element by `rhs`.
+    ///
+    /// The quotient is rounded so that the matching `rem_euclid` is never
+    /// negative, which maps negative coordinates into the right tile.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// let p = Vec2::new([[-1, 17]]);
+    ///
+    /// assert_eq!(p.div_euclid(16), Vec2::new([[-1, 1]]));
+    /// ```
+    #[inline]
+    pub fn div_euclid(mut self, rhs: T) -> Self {
+        for i in 0..COLS {
+            for j in 0..ROWS {
+                self.data[i][j] = self.data[i][j].div_euclid(rhs);
+            }
+        }
+        self
+    }
+
+    /// Computes the least nonnegative remainder of every element by `rhs`.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// let p = Vec2::new([[-1, 17]]);
+    ///
+    /// assert_eq!(p.rem_euclid(16), Vec2::new([[15, 1]]));
+    /// ```
+    #[inline]
+    pub fn rem_euclid(mut self, rhs: T) -> Self {
+        for i in 0..COLS {
+            for j in 0..ROWS {
+                self.data[i][j] = self.data[i][j].rem_euclid(rhs);
+            }
+        }
+        self
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    #[inline]
+    fn compare(&self, other: &Self, f: impl Fn(&T, &T) -> bool) -> Mat<ROWS, COLS, bool> {
+        Mat {
+            data: core::array::from_fn(|i| {
+                core::array::from_fn(|j| f(&self.data[i][j], &other.data[i][j]))
+            }),
+        }
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: PartialEq,
+{
+    /// Compares the matrices element-wise with `==`, like GLSL's `equal`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let a = Vec3::new([[1.0, 2.0, 3.0]]);
+    /// let b = Vec3::new([[2.0, 2.0, 2.0]]);
+    ///
+    /// assert_eq!(a.cmp_eq(&b), Vec3::new([[false, true, false]]));
+    /// ```
+    #[inline]
+    pub fn cmp_eq(&self, other: &Self) -> Mat<ROWS, COLS, bool> {
+        self.compare(other, |a, b| a == b)
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: PartialOrd,
+{
+    /// Compares the matrices element-wise with `<`, like GLSL's `lessThan`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let a = Vec3::new([[1.0, 2.0, 3.0]]);
+    /// let b = Vec3::new([[2.0, 2.0, 2.0]]);
+    ///
+    /// assert_eq!(a.cmp_lt(&b), Vec3::new([[true, false, false]]));
+    /// ```
+    #[inline]
+    pub fn cmp_lt(&self, other: &Self) -> Mat<ROWS, COLS, bool> {
+        self.compare(other, |a, b| a < b)
+    }
+
+    /// Compares the matrices element-wise with `<=`, like GLSL's `lessThanEqual`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let a = Vec3::new([[1.0, 2.0, 3.0]]);
+    /// let b = Vec3::new([[2.0, 2.0, 2.0]]);
+    ///
+    /// assert_eq!(a.cmp_le(&b), Vec3::new([[true, true, false]]));
+    /// ```
+    #[inline]
+    pub fn cmp_le(&self, other: &Self) -> Mat<ROWS, COLS, bool> {
+        self.compare(other, |a, b| a <= b)
+    }
+
+    /// Compares the matrices element-wise with `>`, like GLSL's `greaterThan`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let a = Vec3::new([[1.0, 2.0, 3.0]]);
+    /// let b = Vec3::new([[2.0, 2.0, 2.0]]);
+    ///
+    /// assert_eq!(a.cmp_gt(&b), Vec3::new([[false, false, true]]));
+    /// ```
+    #[inline]
+    pub fn cmp_gt(&self, other: &Self) -> Mat<ROWS, COLS, bool> {
+        self.compare(other, |a, b| a > b)
+    }
+
+    /// Compares the matrices element-wise with `>=`, like GLSL's `greaterThanEqual`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let a = Vec3::new([[1.0, 2.0, 3.0]]);
+    /// let b = Vec3::new([[2.0, 2.0, 2.0]]);
+    ///
+    /// assert_eq!(a.cmp_ge(&b), Vec3::new([[false, true, true]]));
+    /// ```
+    #[inline]
+    pub fn cmp_ge(&self, other: &Self) -> Mat<ROWS, COLS, bool> {
+        self.compare(other, |a, b| a >= b)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, bool> {
+    /// Returns `true` if any element is `true`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// assert!(Vec3::new([[false, true, false]]).any());
+    /// assert!(!Vec3::new([[false, false, false]]).any());
+    /// ```
+    #[inline]
+    pub fn any(&self) -> bool {
+        self.data.iter().flatten().any(|&b| b)
+    }
+
+    /// Returns `true` if every element is `true`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let (min, max) = (Vec3::new([[0.0, 0.0, 0.0]]), Vec3::new([[1.0, 1.0, 1.0]]));
+    /// let p = Vec3::new([[0.5, 1.0, 0.0]]);
+    ///
+    /// assert!(p.cmp_ge(&min).all() && p.cmp_le(&max).all());
+    /// ```
+    #[inline]
+    pub fn all(&self) -> bool {
+        self.data.iter().flatten().all(|&b| b)
+    }
+
+    /// Returns the number of `true` elements.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::<2, 2, i32>::new([[1, 5], [3, 2]]);
+    /// let b = Mat::<2, 2, i32>::new([[2, 2], [2, 2]]);
+    ///
+    /// assert_eq!(a.cmp_gt(&b).count_true(), 2);
+    /// ```
+    #[inline]
+    pub fn count_true(&self) -> usize {
+        self.data.iter().flatten().filter(|&&b| b).count()
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Copy,
+{
+    /// Picks each element from `a` where `mask` is `true` and from `b` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat, Vec3};
+    ///
+    /// let a = Vec3::new([[1.0, -2.0, 3.0]]);
+    /// let zero = Vec3::new([[0.0; 3]]);
+    /// let positive = Mat::select(&a.cmp_gt(&zero), &a, &zero);
+    ///
+    /// assert_eq!(positive, Vec3::new([[1.0, 0.0, 3.0]]));
+    /// ```
+    #[inline]
+    pub fn select(mask: &Mat<ROWS, COLS, bool>, a: &Self, b: &Self) -> Self {
+        Mat {
+            data: core::array::from_fn(|i| {
+                core::array::from_fn(|j| {
+                    if mask.data[i][j] {
+                        a.data[i][j]
+                    } else {
+                        b.data[i][j]
+                    }
+                })
+            }),
+        }
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Conjugate,
+{
+    /// Computes the element-wise complex conjugate of the matrix.
+    ///
+    /// For real element types this returns the matrix unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::new([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(a.conjugate(), a);
+    /// ```
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        Mat {
+            data: core::array::from_fn(|i| core::array::from_fn(|j| self.data[i][j].conjugate())),
+        }
+    }
+
+    /// Computes the conjugate transpose (Hermitian adjoint) of the matrix.
+    ///
+    /// For real element types this is the same as `transpose`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::<3, 2, i32>::new([[1, 3, -4], [-2, 0, 5]]);
+    ///
+    /// assert_eq!(a.conjugate_transpose(), a.transpose());
+    /// ```
+    #[inline]
+    pub fn conjugate_transpose(&self) -> Mat<COLS, ROWS, T> {
+        Mat {
+            data: core::array::from_fn(|i| core::array::from_fn(|j| self.data[j][i].conjugate())),
+        }
+    }
+
+    /// Computes the conjugate transpose of the matrix.
+    ///
+    /// This is a shorthand for `Mat::conjugate_transpose()`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::<3, 2, i32>::new([[1, 3, -4], [-2, 0, 5]]);
+    ///
+    /// assert_eq!(a.hermitian(), a.T());
+    /// ```
+    #[inline]
+    pub fn hermitian(&self) -> Mat<COLS, ROWS, T> {
+        self.conjugate_transpose()
+    }
+}
+
+impl<T, const SIZE: usize> Mat<SIZE, SIZE, T>
+where
+    T: Conjugate + core::ops::Sub<Output = T>,
+{
+    /// Returns `true` if the matrix equals its conjugate transpose, with every
+    /// element within `eps` of its mirrored counterpart.
+    ///
+    /// For real element types this checks that the matrix is symmetric. A NaN
+    /// element, or a NaN `eps`, makes the check fail.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::new([[1.0, 2.0], [2.0, 3.0]]);
+    /// let b = Mat::new([[1.0, 2.0], [2.5, 3.0]]);
+    ///
+    /// assert!(a.is_hermitian(0.0));
+    /// assert!(!b.is_hermitian(0.1));
+    /// ```
+    pub fn is_hermitian(&self, eps: T::Real) -> bool {
+        for i in 0..SIZE {
+            for j in 0..=i {
+                let diff = self.data[i][j].abs_diff(self.data[j][i].conjugate());
+                if !matches!(
+                    diff.partial_cmp(&eps),
+                    Some(core::cmp::Ordering::Less | core::cmp::Ordering::Equal)
+                ) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Copy + core::ops::Mul<Output = T> + core::ops::AddAssign,
+{
+    /// Computes `self += alpha * x` in place.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut y = Mat::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let x = Mat::new([[1.0, 1.0], [0.5, 0.0]]);
+    /// y.axpy(2.0, &x);
+    ///
+    /// assert_eq!(y, Mat::new([[3.0, 4.0], [4.0, 4.0]]));
+    /// ```
+    #[inline]
+    pub fn axpy(&mut self, alpha: T, x: &Self) {
+        for i in 0..COLS {
+            for j in 0..ROWS {
+                self.data[i][j] += alpha * x.data[i][j];
+            }
+        }
+    }
+
+    /// Computes `self += alpha * x` in place.
+    ///
+    /// This is a shorthand for `Mat::axpy()`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let mut y = Vec3::new([[1.0, 2.0, 3.0]]);
+    /// y.scaled_add(-1.0, &Vec3::new([[1.0, 1.0, 1.0]]));
+    ///
+    /// assert_eq!(y, Vec3::new([[0.0, 1.0, 2.0]]));
+    /// ```
+    #[inline]
+    pub fn scaled_add(&mut self, alpha: T, x: &Self) {
+        self.axpy(alpha, x)
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Copy + Zero + core::ops::Add<Output = T>,
+{
+    /// Returns the sum of all elements.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 2, i32>::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(m.sum(), 10);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .fold(T::zero(), |acc, &x| acc + x)
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Copy + UnitOne + core::ops::Mul<Output = T>,
+{
+    /// Returns the product of all elements.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 2, i32>::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(m.element_product(), 24);
+    /// ```
+    #[inline]
+    pub fn element_product(&self) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .fold(T::unit_one(), |acc, &x| acc * x)
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Copy + PartialOrd,
+{
+    /// Returns the smallest element.
+    ///
+    /// Incomparable elements such as NaN are skipped, unless the first element is one.
+    ///
+    /// # Panics
+    /// Panics if the matrix has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// assert_eq!(Vec3::new([[2.0, -1.0, 5.0]]).min_element(), -1.0);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .copied()
+            .reduce(|a, b| if b < a { b } else { a })
+            .expect("matrix has no elements")
+    }
+
+    /// Returns the largest element.
+    ///
+    /// Incomparable elements such as NaN are skipped, unless the first element is one.
+    ///
+    /// # Panics
+    /// Panics if the matrix has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// assert_eq!(Vec3::new([[2.0, -1.0, 5.0]]).max_element(), 5.0);
+    /// ```
+    #[inline]
+    pub fn max_element(&self) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .copied()
+            .reduce(|a, b| if b > a { b } else { a })
+            .expect("matrix has no elements")
+    }
+
+    /// Clamps every element into `[lo, hi]`.
+    ///
+    /// This takes `self` by value so that it is picked over `Ord::clamp`, which
+    /// would clamp the whole matrix in the lexicographic order.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec4;
+    ///
+    /// let v = Vec4::new([[-2, 0, 5, 9]]);
+    ///
+    /// assert_eq!(v.clamp(0, 5), Vec4::new([[0, 0, 5, 5]]));
+    /// ```
+    #[inline]
+    pub fn clamp(self, lo: T, hi: T) -> Self {
+        Self {
+            data: self.data.map(|col| {
+                col.map(|x| {
+                    if x < lo {
+                        lo
+                    } else if x > hi {
+                        hi
+                    } else {
+                        x
+                    }
+                })
+            }),
+        }
+    }
+}
+
+impl<T: Float, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    /// Returns the mean of all elements.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 2, f32>::new([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(m.mean(), 2.5);
+    /// ```
+    #[inline]
+    pub fn mean(&self) -> T {
+        let n = self
+            .data
+            .iter()
+            .flatten()
+            .fold(T::zero(), |n, _| n + T::unit_one());
+        self.sum() / n
+    }
+}
+
+impl<T: Float, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    /// Returns the element-wise reciprocal `1 / x`.
+    ///
+    /// Zero elements follow IEEE division and become infinite with the sign of the
+    /// zero, so check [`is_finite`](Self::is_finite) if that matters.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let v = Vec3::new([[2.0, -0.0, 0.25]]);
+    ///
+    /// assert_eq!(v.recip(), Vec3::new([[0.5, f32::NEG_INFINITY, 4.0]]));
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Self {
+        Mat {
+            data: self.data.map(|col| col.map(|x| T::unit_one() / x)),
+        }
+    }
+
+    /// Returns `true` if no element is infinite or NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// assert!(Vec3::new([[1.0, 2.0, 3.0]]).is_finite());
+    /// assert!(!Vec3::new([[1.0, f32::INFINITY, 3.0]]).is_finite());
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.data.iter().flatten().all(|x| x.is_finite())
+    }
+
+    /// Returns `true` if any element is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// assert!(Vec3::new([[1.0, f32::NAN, 3.0]]).has_nan());
+    /// assert!(!Vec3::new([[1.0, f32::INFINITY, 3.0]]).has_nan());
+    /// ```
+    #[inline]
+    pub fn has_nan(&self) -> bool {
+        self.data.iter().flatten().any(|x| x.is_nan())
+    }
+
+    /// Panics if an element is infinite or NaN, naming `context` and the
+    /// `(row, col)` of the first such element in column-major order.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use mats::Mat2;
+    ///
+    /// let m = Mat2::from_rows_array_2d([[1.0, 0.0], [f32::NAN, 1.0]]);
+    /// // panics with "transform: non-finite element at (1, 0)"
+    /// m.assert_finite("transform");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn assert_finite(&self, context: &str) {
+        for (j, col) in self.data.iter().enumerate() {
+            if let Some(i) = col.iter().position(|x| !x.is_finite()) {
+                panic!("{context}: non-finite element at ({i}, {j})");
+            }
+        }
+    }
+
+    /// Rescales the elements affinely so that the smallest becomes `lo` and the
+    /// largest becomes `hi`.
+    ///
+    /// Returns `None` if all elements are equal, so there is no range to rescale.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec4;
+    ///
+    /// let heights = Vec4::new([[2.0, 4.0, 3.0, 6.0]]);
+    ///
+    /// assert_eq!(heights.normalize_to_range(0.0, 1.0), Some(Vec4::new([[0.0, 0.5, 0.25, 1.0]])));
+    /// assert_eq!(Vec4::new([[1.0; 4]]).normalize_to_range(0.0, 1.0), None);
+    /// ```
+    #[inline]
+    pub fn normalize_to_range(&self, lo: T, hi: T) -> Option<Self> {
+        let min = self.min_element();
+        let max = self.max_element();
+        if max.partial_cmp(&min) != Some(core::cmp::Ordering::Greater) {
+            return None;
+        }
+        let scale = (hi - lo) / (max - min);
+        Some(Self {
+            data: self.data.map(|col| col.map(|x| lo + (x - min) * scale)),
+        })
+    }
+
+    /// Returns the `p`-th percentile of the elements by the nearest-rank method,
+    /// so `0.0` gives the smallest element and `100.0` the largest.
+    ///
+    /// Returns `None` if the matrix is empty or contains NaN, or if `p` is not in
+    /// `[0, 100]`. Sorts a copy of the elements, without allocating.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let m = Mat::<2, 3, f32>::from_rows_array_2d([[5.0, 1.0, 4.0], [2.0, 3.0, 6.0]]);
+    ///
+    /// assert_eq!(m.percentile(0.0), Some(1.0));
+    /// assert_eq!(m.percentile(50.0), Some(4.0));
+    /// assert_eq!(m.percentile(100.0), Some(6.0));
+    /// assert_eq!(m.percentile(101.0), None);
+    /// ```
+    #[inline]
+    pub fn percentile(&self, p: f64) -> Option<T> {
+        if ROWS * COLS == 0 || !(0.0..=100.0).contains(&p) || self.has_nan() {
+            return None;
+        }
+        let mut data = self.data;
+        let sorted = data.as_flattened_mut();
+        sorted.sort_unstable_by(|a, b| a.partial_cmp(b).expect("no NaN"));
+        // Rounds half up; `f64::round` is not available without `std`.
+        let rank = (p / 100.0 * (sorted.len() - 1) as f64 + 0.5) as usize;
+        Some(sorted[rank])
+    }
+}
+
+impl<T: Float, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    /// Returns the `x` minimizing `|self * x - rhs|`, the least-squares solution of
+    /// an overdetermined system.
+    ///
+    /// The system is reduced with Householder reflections (a QR factorization), so
+    /// unlike the normal equations `selfᵀ self x = selfᵀ rhs` it does not square the
+    /// condition number. For a square matrix this solves `self * x = rhs`.
+    ///
+    /// Returns `None` if there are fewer rows than columns or the columns are
+    /// linearly dependent to working precision.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat, Vec, Vec2};
+    ///
+    /// // The line through (0, 1), (1, 3) and (2, 5).
+    /// let a = Mat::<3, 2, f64>::from_rows_array_2d([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+    /// let b = Vec::<3, f64>::new([[1.0, 3.0, 5.0]]);
+    ///
+    /// let x = a.solve_least_squares(&b).unwrap();
+    /// assert!((x - Vec2::new([[1.0, 2.0]])).raw_data()[0].iter().all(|e| e.abs() < 1e-12));
+    /// ```
+    pub fn solve_least_squares(&self, rhs: &Vec<ROWS, T>) -> Option<Vec<COLS, T>> {
+        if ROWS < COLS {
+            return None;
+        }
+        let norm = |col: &[T]| col.iter().fold(T::zero(), |s, &x| s + x * x).sqrt();
+        let rows = (0..ROWS).fold(T::zero(), |n, _| n + T::unit_one());
+        let scale = self.data.iter().fold(T::zero(), |m, col| m.max(norm(col)));
+        let tolerance = scale * rows * T::EPSILON;
+
+        let mut a = self.data;
+        let mut b = rhs.data[0];
+        let mut diag = [T::zero(); COLS];
+        for k in 0..COLS {
+            let length = norm(&a[k][k..]);
+            // Also rejects NaN.
+            if length.partial_cmp(&tolerance) != Some(core::cmp::Ordering::Greater) {
+                return None;
+            }
+            // Reflect the column onto `alpha e_k`, choosing the sign that avoids
+            // cancellation in `v = x - alpha e_k`.
+            let alpha = if a[k][k] > T::zero() { -length } else { length };
+            a[k][k] = a[k][k] - alpha;
+            diag[k] = alpha;
+
+            let v = a[k];
+            let v2 = v[k..].iter().fold(T::zero(), |s, &x| s + x * x);
+            let reflect = |x: &mut [T; ROWS]| {
+                let dot = v[k..]
+                    .iter()
+                    .zip(&x[k..])
+                    .fold(T::zero(), |s, (&v, &x)| s + v * x);
+                let f = (dot + dot) / v2;
+                for (x, &v) in x[k..].iter_mut().zip(&v[k..]) {
+                    *x = *x - f * v;
+                }
+            };
+            a[k + 1..].iter_mut().for_each(reflect);
+            reflect(&mut b);
+        }
+
+        // Back substitution with `R`, whose diagonal is `diag` and whose entries
+        // above it are left in `a`.
+        let mut x = [T::zero(); COLS];
+        for k in (0..COLS).rev() {
+            let tail = (k + 1..COLS).fold(T::zero(), |s, j| s + a[j][k] * x[j]);
+            x[k] = (b[k] - tail) / diag[k];
+        }
+        Some(Vec::new([x]))
+    }
+}
+
+impl<T: Float, const N: usize> Mat<N, N, T> {
+    /// Solves the tridiagonal system `A x = rhs` with the Thomas algorithm in `O(N)`,
+    /// without forming `A`.
+    ///
+    /// `diag[i]` is `A[(i, i)]`, `lower[i]` is `A[(i, i - 1)]` and `upper[i]` is
+    /// `A[(i, i + 1)]`, so `lower[0]` and `upper[N - 1]` are ignored.
+    ///
+    /// The algorithm does not pivot. Returns `None` if a pivot is zero, which cannot
+    /// happen for diagonally dominant systems.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat3, Vec3};
+    ///
+    /// // [4 1 0]       [5]
+    /// // [1 4 1] x  =  [6]
+    /// // [0 1 4]       [5]
+    /// let x = Mat3::solve_tridiagonal(
+    ///     &[0.0, 1.0, 1.0],
+    ///     &[4.0, 4.0, 4.0],
+    ///     &[1.0, 1.0, 0.0],
+    ///     &Vec3::new([[5.0, 6.0, 5.0]]),
+    /// );
+    ///
+    /// assert_eq!(x, Some(Vec3::new([[1.0, 1.0, 1.0]])));
+    /// ```
+    pub fn solve_tridiagonal(
+        lower: &[T; N],
+        diag: &[T; N],
+        upper: &[T; N],
+        rhs: &Vec<N, T>,
+    ) -> Option<Vec<N, T>> {
+        let d = &rhs.data[0];
+        let mut c = [T::zero(); N];
+        let mut x = [T::zero(); N];
+        for i in 0..N {
+            let (c_prev, x_prev) = if i == 0 {
+                (T::zero(), T::zero())
+            } else {
+                (c[i - 1], x[i - 1])
+            };
+            let a = if i == 0 { T::zero() } else { lower[i] };
+            let pivot = diag[i] - a * c_prev;
+            // Also rejects a NaN pivot.
+            if pivot.abs().partial_cmp(&T::zero()) != Some(core::cmp::Ordering::Greater) {
+                return None;
+            }
+            c[i] = upper[i] / pivot;
+            x[i] = (d[i] - a * x_prev) / pivot;
+        }
+        for i in (0..N.saturating_sub(1)).rev() {
+            x[i] = x[i] - c[i] * x[i + 1];
+        }
+        Some(Vec::new([x]))
+    }
+
+    /// Solves `self * x = rhs` for a tridiagonal `self`, see
+    /// [`solve_tridiagonal`](Self::solve_tridiagonal).
+    ///
+    /// Returns `None` if an element outside the three bands exceeds `epsilon` in
+    /// magnitude, or if a pivot is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat3, Vec3};
+    ///
+    /// let a = Mat3::from_rows_array_2d([[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0]]);
+    /// let b = Vec3::new([[5.0, 6.0, 5.0]]);
+    ///
+    /// assert_eq!(a.solve_as_tridiagonal(&b, 0.0), Some(Vec3::new([[1.0, 1.0, 1.0]])));
+    /// assert_eq!(Mat3::init(1.0).solve_as_tridiagonal(&b, 0.5), None);
+    /// ```
+    pub fn solve_as_tridiagonal(&self, rhs: &Vec<N, T>, epsilon: T) -> Option<Vec<N, T>> {
+        for j in 0..N {
+            for i in 0..N {
+                if i.abs_diff(j) > 1 && self.data[j][i].abs() > epsilon {
+                    return None;
+                }
+            }
+        }
+        let lower = core::array::from_fn(|i| {
+            if i > 0 {
+                self.data[i - 1][i]
+            } else {
+                T::zero()
+            }
+        });
+        let diag = core::array::from_fn(|i| self.data[i][i]);
+        let upper = core::array::from_fn(|i| {
+            if i + 1 < N {
+                self.data[i + 1][i]
+            } else {
+                T::zero()
+            }
+        });
+        Self::solve_tridiagonal(&lower, &diag, &upper, rhs)
+    }
+}
+
+impl<T: Float, const N: usize> Vec<N, T> {
+    /// Returns the population variance of the components.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec4;
+    ///
+    /// assert_eq!(Vec4::new([[1.0, 2.0, 3.0, 4.0]]).variance(), 1.25);
+    /// assert_eq!(Vec4::new([[3.0, 3.0, 3.0, 3.0]]).variance(), 0.0);
+    /// ```
+    #[inline]
+    pub fn variance(&self) -> T {
+        let mean = self.mean();
+        let mut squares = *self;
+        for x in squares.data[0].iter_mut() {
+            *x = (*x - mean) * (*x - mean);
+        }
+        squares.mean()
+    }
+
+    /// Returns the population standard deviation of the components.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// assert_eq!(Vec2::new([[1.0, 5.0]]).std_dev(), 2.0);
+    /// ```
+    #[inline]
+    pub fn std_dev(&self) -> T {
+        self.variance().sqrt()
+    }
+}
+
+impl<T: Float, const N: usize> Vec<N, T> {
+    /// Create a vector of `N` evenly spaced values from `start` to `end`, both
+    /// included.
+    ///
+    /// The endpoints are exact; a single element is `start`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec;
+    ///
+    /// assert_eq!(Vec::<5, f64>::linspace(0.0, 1.0), Vec::new([[0.0, 0.25, 0.5, 0.75, 1.0]]));
+    /// assert_eq!(Vec::<1, f64>::linspace(2.0, 3.0), Vec::new([[2.0]]));
+    /// ```
+    #[inline]
+    pub fn linspace(start: T, end: T) -> Self {
+        let mut steps = T::zero();
+        for _ in 1..N {
+            steps += T::unit_one();
+        }
+        let step = (end - start) / steps;
+        let mut index = T::zero();
+        Self::from_fn(|i, _| {
+            let x = if i == 0 {
+                start
+            } else if i + 1 == N {
+                end
+            } else {
+                start + step * index
+            };
+            index += T::unit_one();
+            x
+        })
+    }
+}
+
+impl<T: Copy, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    /// Create the coordinate matrices of the grid spanned by `xs` and `ys`, like
+    /// `numpy.meshgrid` with the default `xy` indexing.
+    ///
+    /// In the first matrix every row is `xs`, and in the second every column is
+    /// `ys`, so element `(i, j)` of the pair is the grid point `(xs[j], ys[i])`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat, RowVec, Vec};
+    ///
+    /// let xs = RowVec::<3, i32>::from([1, 2, 3]);
+    /// let ys = Vec::<2, i32>::new([[10, 20]]);
+    /// let (x, y) = Mat::meshgrid(&xs, &ys);
+    ///
+    /// assert_eq!(x.to_rows_array_2d(), [[1, 2, 3], [1, 2, 3]]);
+    /// assert_eq!(y.to_rows_array_2d(), [[10, 10, 10], [20, 20, 20]]);
+    /// ```
+    #[inline]
+    pub fn meshgrid(xs: &RowVec<COLS, T>, ys: &Vec<ROWS, T>) -> (Self, Self) {
+        (
+            Self::from_fn(|_, j| xs.data[j][0]),
+            Self::from_fn(|i, _| ys.data[0][i]),
+        )
+    }
+}
+
+impl<T: Float, const N: usize> Vec<N, T> {
+    /// Returns this vector scaled to unit length, or `fallback` if it has no
+    /// direction: its length is zero, infinite or NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// let up = Vec2::new([[0.0, 1.0]]);
+    ///
+    /// assert_eq!(Vec2::new([[3.0, 4.0]]).normalize_or(up), Vec2::new([[0.6, 0.8]]));
+    /// assert_eq!(Vec2::new([[0.0, 0.0]]).normalize_or(up), up);
+    /// ```
+    #[inline]
+    pub fn normalize_or(self, fallback: Self) -> Self {
+        let length = self.inner_product(&self).sqrt();
+        if length > T::zero() && length.is_finite() {
+            self / length
+        } else {
+            fallback
+        }
+    }
+
+    /// Returns this vector with its length clamped to `[min, max]`.
+    ///
+    /// Vectors already in range are returned unchanged, bit for bit, since the
+    /// squared length is compared without a square root. The zero vector has no
+    /// direction to scale along and is returned as is.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// let v = Vec2::new([[3.0, 4.0]]);
+    ///
+    /// assert_eq!(v.clamp_length(1.0, 2.5), Vec2::new([[1.5, 2.0]]));
+    /// assert_eq!(v.clamp_length(10.0, 20.0), Vec2::new([[6.0, 8.0]]));
+    /// assert_eq!(v.clamp_length(1.0, 5.0), v);
+    /// ```
+    #[inline]
+    pub fn clamp_length(self, min: T, max: T) -> Self {
+        let squared = self.inner_product(&self);
+        if squared > max * max {
+            self * (max / squared.sqrt())
+        } else if squared < min * min && squared > T::zero() {
+            self * (min / squared.sqrt())
+        } else {
+            self
+        }
+    }
+
+    /// Returns this vector shortened to at most `max`, see
+    /// [`clamp_length`](Self::clamp_length).
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let v = Vec3::new([[0.0, -6.0, 8.0]]);
+    ///
+    /// assert_eq!(v.clamp_length_max(5.0), Vec3::new([[0.0, -3.0, 4.0]]));
+    /// assert_eq!(v.clamp_length_max(10.0), v);
+    /// ```
+    #[inline]
+    pub fn clamp_length_max(self, max: T) -> Self {
+        let squared = self.inner_product(&self);
+        if squared > max * max {
+            self * (max / squared.sqrt())
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: Float> Vec2<T> {
+    /// Creates the vector at `radius` and counterclockwise `angle` from `+x`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Deg, Vec2};
+    ///
+    /// let v = Vec2::from_polar(2.0f64, Deg(90.0));
+    ///
+    /// assert!(v.x().abs() < 1e-15 && v.y() == 2.0);
+    /// ```
+    #[inline]
+    pub fn from_polar(radius: T, angle: impl Angle<T>) -> Self {
+        let Rad(angle) = angle.to_rad();
+        Mat {
+            data: [[radius * angle.cos(), radius * angle.sin()]],
+        }
+    }
+
+    /// Returns the radius and the angle in `(-π, π]` of this vector, the inverse of
+    /// [`from_polar`](Self::from_polar).
+    ///
+    /// The zero vector has no direction and returns a zero angle.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Rad, Vec2};
+    ///
+    /// assert_eq!(Vec2::new([[0.0, -3.0]]).to_polar(), (3.0, Rad(-std::f64::consts::FRAC_PI_2)));
+    /// assert_eq!(Vec2::new([[0.0, 0.0]]).to_polar(), (0.0, Rad(0.0)));
+    /// ```
+    #[inline]
+    pub fn to_polar(&self) -> (T, Rad<T>) {
+        let (x, y) = (self.x(), self.y());
+        let radius = (x * x + y * y).sqrt();
+        if radius == T::zero() {
+            return (radius, Rad(T::zero()));
+        }
+        (radius, Rad(y.atan2(x)))
+    }
+}
+
+impl<T: Float> Vec3<T> {
+    /// Creates a vector from spherical coordinates in the `+y`-up convention of
+    /// [`graphics`](crate::graphics).
+    ///
+    /// `theta` is the polar angle from `+y` and `phi` is the azimuth around `+y`,
+    /// measured from `+z` towards `+x`:
+    /// `(r sin θ sin φ, r cos θ, r sin θ cos φ)`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Deg, Vec3};
+    ///
+    /// let v = Vec3::from_spherical(2.0f64, Deg(90.0), Deg(90.0));
+    ///
+    /// assert_eq!(v.x(), 2.0);
+    /// assert!(v.y().abs() < 1e-15 && v.z().abs() < 1e-15);
+    /// ```
+    #[inline]
+    pub fn from_spherical(radius: T, theta: impl Angle<T>, phi: impl Angle<T>) -> Self {
+        let (Rad(theta), Rad(phi)) = (theta.to_rad(), phi.to_rad());
+        let horizontal = radius * theta.sin();
+        Mat {
+            data: [[
+                horizontal * phi.sin(),
+                radius * theta.cos(),
+                horizontal * phi.cos(),
+            ]],
+        }
+    }
+
+    /// Returns the radius, the polar angle `theta` in `[0, π]` and the azimuth `phi`
+    /// in `(-π, π]` of this vector, the inverse of
+    /// [`from_spherical`](Self::from_spherical).
+    ///
+    /// The zero vector returns zero angles, and a vector along `±y` a zero azimuth.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Rad, Vec3};
+    ///
+    /// let (r, Rad(theta), Rad(phi)) = Vec3::new([[0.0, 0.0, 3.0]]).to_spherical();
+    ///
+    /// assert_eq!((r, theta, phi), (3.0, std::f64::consts::FRAC_PI_2, 0.0));
+    /// assert_eq!(Vec3::new([[0.0, 0.0, 0.0]]).to_spherical(), (0.0, Rad(0.0), Rad(0.0)));
+    /// ```
+    #[inline]
+    pub fn to_spherical(&self) -> (T, Rad<T>, Rad<T>) {
+        let (x, y, z) = (self.x(), self.y(), self.z());
+        let horizontal = (x * x + z * z).sqrt();
+        let radius = (horizontal * horizontal + y * y).sqrt();
+        if radius == T::zero() {
+            return (radius, Rad(T::zero()), Rad(T::zero()));
+        }
+        let phi = if horizontal == T::zero() {
+            T::zero()
+        } else {
+            x.atan2(z)
+        };
+        (radius, Rad(horizontal.atan2(y)), Rad(phi))
+    }
+
+    /// Creates the unit view direction of a camera turned by `yaw` and `pitch`.
+    ///
+    /// Zero yaw and pitch look down `-z`, the forward axis of
+    /// [`look_at`](crate::graphics::look_at). A positive yaw turns left
+    /// (counterclockwise around `+y`) and a positive pitch looks up.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Deg, Vec3};
+    ///
+    /// let forward = Vec3::from_yaw_pitch(0.0f64, 0.0);
+    /// let left = Vec3::from_yaw_pitch(Deg(90.0f64), Deg(0.0));
+    ///
+    /// assert_eq!(forward, Vec3::new([[0.0, 0.0, -1.0]]));
+    /// assert!(left.x() == -1.0 && left.y() == 0.0 && left.z().abs() < 1e-15);
+    /// ```
+    #[inline]
+    pub fn from_yaw_pitch(yaw: impl Angle<T>, pitch: impl Angle<T>) -> Self {
+        let (Rad(yaw), Rad(pitch)) = (yaw.to_rad(), pitch.to_rad());
+        let horizontal = pitch.cos();
+        Mat {
+            data: [[
+                -horizontal * yaw.sin(),
+                pitch.sin(),
+                -horizontal * yaw.cos(),
+            ]],
+        }
+    }
+}
+
+impl<T: Float> Mat2<T> {
+    /// Returns the determinant of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat2;
+    ///
+    /// let m = Mat2::<f32>::from_rows_array_2d([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(m.determinant(), -2.0);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> T {
+        let [[a, c], [b, d]] = self.data;
+        a * d - b * c
+    }
+
+    /// Returns the inverse of the matrix, or `None` if it is singular.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat2;
+    ///
+    /// let m = Mat2::<f32>::from_rows_array_2d([[2.0, 0.0], [0.0, 4.0]]);
+    ///
+    /// assert_eq!(m.inverse(), Some(Mat2::from_rows_array_2d([[0.5, 0.0], [0.0, 0.25]])));
+    /// assert_eq!(Mat2::<f32>::from_rows_array_2d([[1.0, 2.0], [2.0, 4.0]]).inverse(), None);
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+        let [[a, c], [b, d]] = self.data;
+        Some(Mat {
+            data: [[d / det, -c / det], [-b / det, a / det]],
+        })
+    }
+
+    /// Create a counterclockwise rotation matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Deg, Mat2, Vec2};
+    ///
+    /// let r = Mat2::from_angle(Deg(90.0f64));
+    /// let v = r * Vec2::new([[1.0, 0.0]]);
+    ///
+    /// assert!(v.x().abs() < 1e-15 && v.y() == 1.0);
+    /// ```
+    #[inline]
+    pub fn from_angle(angle: impl Angle<T>) -> Self {
+        Self::from_scale_angle(Vec2::new([[T::unit_one(), T::unit_one()]]), angle)
+    }
+
+    /// Create a matrix scaling by `scale` and then rotating counterclockwise by `angle`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat2, Vec2};
+    ///
+    /// let m = Mat2::from_scale_angle(Vec2::new([[2.0f32, 3.0]]), 0.0);
+    ///
+    /// assert_eq!(m.to_rows_array_2d(), [[2.0, 0.0], [0.0, 3.0]]);
+    /// ```
+    #[inline]
+    pub fn from_scale_angle(scale: Vec2<T>, angle: impl Angle<T>) -> Self {
+        let Rad(angle) = angle.to_rad();
+        let (s, c) = (angle.sin(), angle.cos());
+        Mat {
+            data: [
+                [c * scale.x(), s * scale.x()],
+                [-s * scale.y(), c * scale.y()],
+            ],
+        }
+    }
+}
+
+impl<T: Float> Mat3<T> {
+    /// Decompose a 2D transform into translation, rotation, scale and skew, such
+    /// that `self == translate2d(t) * rotate2d(angle) * K * scale2d(s)` with the
+    /// shear `K = [[1, skew], [0, 1]]`.
+    ///
+    /// This follows the CSS/SVG matrix decomposition. The `x` scale is never
+    /// negative: a mirror shows up as a negative `y` scale, and scaling both axes by
+    /// `-1` as a 180° rotation. The angle is in `(-π, π]` and `skew` is the tangent
+    /// of the skew angle.
+    ///
+    /// Returns `None` if the matrix is degenerate (a zero scale) or projective (its
+    /// bottom row is not `[0, 0, 1]`).
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat3, Rad, Vec2};
+    ///
+    /// let m = Mat3::from_rows_array_2d([[0.0, -3.0, 5.0], [2.0, 0.0, 6.0], [0.0, 0.0, 1.0]]);
+    /// let (t, Rad(angle), s, skew) = m.decompose_2d().unwrap();
+    ///
+    /// assert_eq!(t, Vec2::new([[5.0, 6.0]]));
+    /// assert_eq!(angle, std::f64::consts::FRAC_PI_2);
+    /// assert_eq!((s, skew), (Vec2::new([[2.0, 3.0]]), 0.0));
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn decompose_2d(&self) -> Option<(Vec2<T>, Rad<T>, Vec2<T>, T)> {
+        let [[ax, ay, p], [bx, by, q], [tx, ty, w]] = self.data;
+        if p != T::zero() || q != T::zero() || w != T::unit_one() {
+            return None;
+        }
+        let sx = (ax * ax + ay * ay).sqrt();
+        let det = ax * by - ay * bx;
+        if sx == T::zero() || det == T::zero() {
+            return None;
+        }
+        let sy = det / sx;
+        let skew = (ax * bx + ay * by) / det;
+        Some((
+            Vec2::new([[tx, ty]]),
+            Rad(ay.atan2(ax)),
+            Vec2::new([[sx, sy]]),
+            skew,
+        ))
+    }
+
+    /// Returns the mean and the covariance matrix of `points`.
+    ///
+    /// The covariance is the population covariance, the average of
+    /// `(p - mean) * (p - mean)ᵀ`. An empty slice gives NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat3, Vec3};
+    ///
+    /// let points = [Vec3::new([[1.0, 0.0, 0.0]]), Vec3::new([[3.0, 0.0, 0.0]])];
+    /// let (mean, cov) = Mat3::covariance(&points);
+    ///
+    /// assert_eq!(mean, Vec3::new([[2.0, 0.0, 0.0]]));
+    /// assert_eq!(cov.to_rows_array_2d(), [[1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+    /// ```
+    pub fn covariance(points: &[Vec3<T>]) -> (Vec3<T>, Self) {
+        let mut n = T::zero();
+        let mut sum = Vec3::new([[T::zero(); 3]]);
+        for p in points {
+            sum += *p;
+            n += T::unit_one();
+        }
+        let mean = sum / n;
+
+        let mut cov = Self::zero();
+        for p in points {
+            let d = *p - mean;
+            cov += Self::from_outer_product(&d, &d);
+        }
+        (mean, cov / n)
+    }
+
+    /// Returns the inertia tensor of the point masses `(position, mass)` about their
+    /// center of mass.
+    ///
+    /// Each point contributes `m * (|r|² I - r rᵀ)`, with `r` its offset from the
+    /// center of mass.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat3, Vec3};
+    ///
+    /// // Two unit masses on the x axis, 1 away from their center.
+    /// let points = [
+    ///     (Vec3::new([[1.0, 5.0, 0.0]]), 1.0),
+    ///     (Vec3::new([[-1.0, 5.0, 0.0]]), 1.0),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     Mat3::inertia_tensor(&points).to_rows_array_2d(),
+    ///     [[0.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 2.0]]
+    /// );
+    /// ```
+    pub fn inertia_tensor(points: &[(Vec3<T>, T)]) -> Self {
+        let mut total = T::zero();
+        let mut moment = Vec3::new([[T::zero(); 3]]);
+        for &(p, m) in points {
+            moment += p * m;
+            total += m;
+        }
+        let center = moment / total;
+
+        let mut inertia = Self::zero();
+        for &(p, m) in points {
+            let r = p - center;
+            let r2 = r.inner_product(&r);
+            inertia += (Self::unit_one() * r2 - Self::from_outer_product(&r, &r)) * m;
+        }
+        inertia
+    }
+}
+
+impl<T: Float> Mat4<T> {
+    /// Whether the bottom row is `[0, 0, 0, 1]`, so `w` stays `1` for points.
+    #[inline]
+    fn is_affine(&self) -> bool {
+        let (o, l) = (T::zero(), T::unit_one());
+        self.data[0][3] == o && self.data[1][3] == o && self.data[2][3] == o && self.data[3][3] == l
+    }
+
+    /// The 2x2 minors of the top two rows (`s`) and the bottom two rows (`c`), from
+    /// which both the determinant and the inverse are expanded.
+    #[inline(always)]
+    fn half_minors(&self) -> ([T; 6], [T; 6]) {
+        let [
+            [a00, a10, a20, a30],
+            [a01, a11, a21, a31],
+            [a02, a12, a22, a32],
+            [a03, a13, a23, a33],
+        ] = self.data;
+        let s = [
+            a00 * a11 - a10 * a01,
+            a00 * a12 - a10 * a02,
+            a00 * a13 - a10 * a03,
+            a01 * a12 - a11 * a02,
+            a01 * a13 - a11 * a03,
+            a02 * a13 - a12 * a03,
+        ];
+        let c = [
+            a20 * a31 - a30 * a21,
+            a20 * a32 - a30 * a22,
+            a20 * a33 - a30 * a23,
+            a21 * a32 - a31 * a22,
+            a21 * a33 - a31 * a23,
+            a22 * a33 - a32 * a23,
+        ];
+        (s, c)
+    }
+
+    /// The determinant from the minors returned by `half_minors`.
+    #[inline(always)]
+    fn determinant_from_minors(
+        [s0, s1, s2, s3, s4, s5]: [T; 6],
+        [c0, c1, c2, c3, c4, c5]: [T; 6],
+    ) -> T {
+        s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+    }
+
+    /// Returns the determinant of the matrix, expanded over the 2x2 minors of its
+    /// top and bottom halves.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat4;
+    ///
+    /// let m = Mat4::<f64>::translation(1.0, 2.0, 3.0) * 2.0;
+    ///
+    /// assert_eq!(m.determinant(), 16.0);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> T {
+        let (s, c) = self.half_minors();
+        Self::determinant_from_minors(s, c)
+    }
+
+    /// Returns the inverse of the matrix, or `None` if it is singular.
+    ///
+    /// The adjugate is expanded over the same 2x2 minors as `determinant`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat4;
+    ///
+    /// let m = Mat4::<f64>::translation(1.0, 2.0, 3.0) * Mat4::<f64>::scaling(2.0, 4.0, 0.5);
+    ///
+    /// assert_eq!(m.inverse().unwrap() * m, Mat4::<f64>::IDENTITY);
+    /// assert_eq!(Mat4::<f64>::scaling(1.0, 0.0, 1.0).inverse(), None);
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Option<Self> {
+        let (s, c) = self.half_minors();
+        let det = Self::determinant_from_minors(s, c);
+        if det == T::zero() {
+            return None;
+        }
+        let [
+            [a00, a10, a20, a30],
+            [a01, a11, a21, a31],
+            [a02, a12, a22, a32],
+            [a03, a13, a23, a33],
+        ] = self.data;
+        let [s0, s1, s2, s3, s4, s5] = s;
+        let [c0, c1, c2, c3, c4, c5] = c;
+        let inv = Mat::from_rows_array_2d([
+            [
+                a11 * c5 - a12 * c4 + a13 * c3,
+                -a01 * c5 + a02 * c4 - a03 * c3,
+                a31 * s5 - a32 * s4 + a33 * s3,
+                -a21 * s5 + a22 * s4 - a23 * s3,
+            ],
+            [
+                -a10 * c5 + a12 * c2 - a13 * c1,
+                a00 * c5 - a02 * c2 + a03 * c1,
+                -a30 * s5 + a32 * s2 - a33 * s1,
+                a20 * s5 - a22 * s2 + a23 * s1,
+            ],
+            [
+                a10 * c4 - a11 * c2 + a13 * c0,
+                -a00 * c4 + a01 * c2 - a03 * c0,
+                a30 * s4 - a31 * s2 + a33 * s0,
+                -a20 * s4 + a21 * s2 - a23 * s0,
+            ],
+            [
+                -a10 * c3 + a11 * c1 - a12 * c0,
+                a00 * c3 - a01 * c1 + a02 * c0,
+                -a30 * s3 + a31 * s1 - a32 * s0,
+                a20 * s3 - a21 * s1 + a22 * s0,
+            ],
+        ]);
+        Some(inv / det)
+    }
+
+    /// Returns `-1` if the transform mirrors space, `1` if it keeps its orientation,
+    /// and `0` if it is singular or the determinant is NaN.
+    ///
+    /// A mirroring model matrix flips the winding order of its triangles.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat4;
+    ///
+    /// let mirror = Mat4::<f32>::scaling(-1.0, 1.0, 1.0);
+    ///
+    /// assert_eq!(mirror.determinant_sign(), -1.0);
+    /// assert_eq!(Mat4::<f32>::scaling(2.0, 3.0, 0.0).determinant_sign(), 0.0);
+    /// ```
+    #[inline]
+    pub fn determinant_sign(&self) -> T {
+        let det = self.determinant();
+        if det > T::zero() {
+            T::unit_one()
+        } else if det < T::zero() {
+            -T::unit_one()
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Transform the point `p`, as the homogeneous `(p, 1)` divided by its `w`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::<f32>::translation(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(m.transform_point(Vec3::new([[1.0, 1.0, 1.0]])), Vec3::new([[2.0, 3.0, 4.0]]));
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, p: Vec3<T>) -> Vec3<T> {
+        let mut points = [p];
+        self.transform_points(&mut points);
+        points[0]
+    }
+
+    /// Transform the direction `d`, as the homogeneous `(d, 0)`; translation does not apply.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::<f32>::translation(1.0, 2.0, 3.0) * Mat4::<f32>::scaling(2.0, 2.0, 2.0);
+    ///
+    /// assert_eq!(m.transform_vector(Vec3::new([[1.0, 1.0, 1.0]])), Vec3::new([[2.0, 2.0, 2.0]]));
+    /// ```
+    #[inline]
+    pub fn transform_vector(&self, d: Vec3<T>) -> Vec3<T> {
+        let mut dirs = [d];
+        self.transform_vectors(&mut dirs);
+        dirs[0]
+    }
+
+    /// Transform every point of `points` in place, see `transform_point`.
+    ///
+    /// The perspective divide is skipped when the matrix is affine.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::<f32>::scaling(2.0, 3.0, 4.0);
+    /// let mut points = [Vec3::new([[1.0, 1.0, 1.0]]), Vec3::new([[0.0, 1.0, 2.0]])];
+    /// m.transform_points(&mut points);
+    ///
+    /// assert_eq!(points, [Vec3::new([[2.0, 3.0, 4.0]]), Vec3::new([[0.0, 3.0, 8.0]])]);
+    /// ```
+    pub fn transform_points(&self, points: &mut [Vec3<T>]) {
+        let [c0, c1, c2, c3] = self.data;
+        if self.is_affine() {
+            for p in points {
+                let [[x, y, z]] = p.data;
+                p.data = [core::array::from_fn(|i| {
+                    c0[i] * x + c1[i] * y + c2[i] * z + c3[i]
+                })];
+            }
+        } else {
+            for p in points {
+                let [[x, y, z]] = p.data;
+                let w = c0[3] * x + c1[3] * y + c2[3] * z + c3[3];
+                p.data = [core::array::from_fn(|i| {
+                    (c0[i] * x + c1[i] * y + c2[i] * z + c3[i]) / w
+                })];
+            }
+        }
+    }
+
+    /// Transform every direction of `dirs` in place, see `transform_vector`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::<f32>::translation(5.0, 5.0, 5.0);
+    /// let mut dirs = [Vec3::new([[1.0, 0.0, 0.0]])];
+    /// m.transform_vectors(&mut dirs);
+    ///
+    /// assert_eq!(dirs, [Vec3::new([[1.0, 0.0, 0.0]])]);
+    /// ```
+    pub fn transform_vectors(&self, dirs: &mut [Vec3<T>]) {
+        let [c0, c1, c2, _] = self.data;
+        for d in dirs {
+            let [[x, y, z]] = d.data;
+            d.data = [core::array::from_fn(|i| c0[i] * x + c1[i] * y + c2[i] * z)];
+        }
+    }
+
+    /// Return the transformed copies of `points`, see `transform_point`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat4, Vec3};
+    ///
+    /// let m = Mat4::<f32>::translation(1.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(m.transformed_points(&[Vec3::new([[0.0, 0.0, 0.0]])]), [Vec3::new([[1.0, 0.0, 0.0]])]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn transformed_points(&self, points: &[Vec3<T>]) -> std::vec::Vec<Vec3<T>> {
+        let mut points = points.to_vec();
+        self.transform_points(&mut points);
+        points
+    }
+}
+
+/// Bit-pattern keys and quantization for float matrices.
+macro_rules! impl_bits_key {
+    ($(($t:tt, $bits:ty)),*) => {
+        $(
+            impl<const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, $t> {
+                #[doc = concat!("Return the bit patterns of the elements, see `", stringify!($t), "::to_bits`.")]
+                ///
+                /// The result implements `Hash`, `Eq` and `Ord`, so it can key a `HashMap` or
+                /// `BTreeMap` to deduplicate float vectors. Two keys are equal exactly when the
+                /// elements are bitwise identical: `0.0` and `-0.0` are different keys, and a
+                /// NaN only matches a NaN with the same payload. Call `quantized` first to
+                /// merge nearly equal values and both zeros.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Vec3;
+                ///
+                /// let a = Vec3::<f32>::new([[1.0, 2.0, 3.0]]);
+                ///
+                /// assert_eq!(a.to_bits_key(), Vec3::<f32>::new([[1.0, 2.0, 3.0]]).to_bits_key());
+                /// assert_ne!(Vec3::<f32>::new([[0.0, 0.0, 0.0]]).to_bits_key(), Vec3::<f32>::new([[-0.0, 0.0, 0.0]]).to_bits_key());
+                /// ```
+                #[inline]
+                pub fn to_bits_key(&self) -> Mat<ROWS, COLS, $bits> {
+                    Mat {
+                        data: self.data.map(|col| col.map(<$t>::to_bits)),
+                    }
+                }
+
+                /// Snap every element to the nearest multiple of `epsilon`.
+                ///
+                /// `-0.0` becomes `0.0`, so quantized values that compare equal also have
+                /// equal `to_bits_key`s. NaN stays NaN.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Vec3;
+                ///
+                /// let a = Vec3::<f32>::new([[1.0001, -0.0001, 2.4999]]);
+                ///
+                /// assert_eq!(a.quantized(0.5), Vec3::new([[1.0, 0.0, 2.5]]));
+                /// ```
+                #[inline]
+                pub fn quantized(&self, epsilon: $t) -> Self {
+                    Mat {
+                        data: self.data.map(|col| {
+                            col.map(|x| crate::traits::float_intrinsic!($t, round, round, x / epsilon) * epsilon + 0.0)
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bits_key!((f32, u32), (f64, u64));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transpose_initializes_every_element() {
+        let a = Mat::<2, 3, bool>::new([[true, false], [false, true], [true, true]]);
+
+        assert_eq!(a.T(), Mat::new([[true, false, true], [false, true, true]]));
+        assert_eq!(a.T().T(), a);
+    }
+
+    #[test]
+    fn test_dot_matches_explicit_sums() {
+        let a = Mat::<4, 4, f32>::new([
+            [0.5, 1.25, -2.0, 3.0],
+            [4.0, -0.75, 6.5, 7.0],
+            [-8.0, 9.5, 10.0, -11.25],
+            [12.0, 13.0, -14.5, 15.0],
+        ]);
+        let b = Mat::<4, 4, f32>::new([
+            [1.5, -2.0, 3.25, 4.0],
+            [-5.0, 6.0, 7.75, -8.0],
+            [9.0, 10.5, -11.0, 12.0],
+            [13.0, -14.0, 15.5, 16.25],
+        ]);
+        let c = a * b;
+
+        for i in 0..4 {
+            for j in 0..4 {
+                let expected = a[(i, 0)] * b[(0, j)]
+                    + a[(i, 1)] * b[(1, j)]
+                    + a[(i, 2)] * b[(2, j)]
+                    + a[(i, 3)] * b[(3, j)];
+                assert_eq!(c[(i, j)], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fma_bit_patterns() {
+        // `0.1 * 10.0` rounds to exactly 1.0, while the fused product keeps the
+        // 2⁻⁵⁴ representation error of 0.1. Both paths must give these bits on every
+        // target, with `std` and with `libm`.
+        let a = Mat::<2, 2, f64>::new([[-1.0, 0.5], [0.1, 3.0]]);
+        let b = Mat::<2, 1, f64>::new([[1.0, 10.0]]);
+
+        let bits = |m: Mat<2, 1, f64>| m.to_cols_array_2d()[0].map(f64::to_bits);
+        assert_eq!(bits(a.dot(&b)), [0x0000000000000000, 0x403e800000000000]);
+        assert_eq!(
+            bits(a.dot_fma(&b)),
+            [0x3c90000000000000, 0x403e800000000000]
+        );
+
+        let [[x, y]] = b.mul_add(-1.0, &Mat::new([[0.0, 10.0]])).to_cols_array_2d();
+        assert_eq!((x.to_bits(), y.to_bits()), (0xbff0000000000000, 0));
+        assert_eq!(
+            Float::mul_add(0.1f32, 10.0, -1.0).to_bits(),
+            0x32800000,
+            "f32 fma keeps the 2⁻²⁶ error of 0.1"
+        );
+    }
+
+    #[test]
+    fn test_polar_and_spherical_round_trip() {
+        use core::f64::consts::PI;
+
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+        for i in 0..12 {
+            // Stay inside (-π, π), away from the branch cut of atan2.
+            let angle = -PI + (i as f64 + 0.5) * PI / 6.0;
+            let (r, Rad(back)) = Vec2::from_polar(2.5, angle).to_polar();
+            assert!(close(r, 2.5) && close(back, angle), "polar {angle}");
+
+            for j in 1..8 {
+                // Avoid the poles, where the azimuth is undefined.
+                let theta = j as f64 * PI / 8.0;
+                let v = Vec3::from_spherical(1.5, theta, angle);
+                let (r, Rad(t), Rad(p)) = v.to_spherical();
+                assert!(close(r, 1.5) && close(t, theta) && close(p, angle), "{v:?}");
+
+                let d = Vec3::from_yaw_pitch(angle, theta - PI / 2.0);
+                assert!(close(d.inner_product(&d), 1.0));
+            }
+        }
+
+        assert_eq!(Vec2::<f64>::zero().to_polar(), (0.0, Rad(0.0)));
+        assert_eq!(
+            Vec3::<f64>::zero().to_spherical(),
+            (0.0, Rad(0.0), Rad(0.0))
+        );
+        assert_eq!(
+            Vec3::new([[0.0, -2.0, 0.0]]).to_spherical(),
+            (2.0, Rad(PI), Rad(0.0))
+        );
+    }
+
+    #[test]
+    fn test_safe_lengths() {
+        let zero = Vec3::<f64>::zero();
+        let fallback = Vec3::new([[0.0, 0.0, 1.0]]);
+        assert_eq!(zero.normalize_or(fallback), fallback);
+        assert_eq!(zero.clamp_length(1.0, 2.0), zero);
+        assert_eq!(zero.clamp_length_max(2.0), zero);
+        assert_eq!(zero.recip(), Vec3::new([[f64::INFINITY; 3]]));
+        let huge = Vec3::new([[f64::INFINITY, 0.0, 0.0]]);
+        assert_eq!(huge.normalize_or(fallback), fallback);
+
+        // Exactly at the maximum length: untouched, bit for bit.
+        let v = Vec3::new([[0.1, 0.2, 0.3]]);
+        let length = v.inner_product(&v).sqrt();
+        let bits = |v: Vec3<f64>| v.to_cols_array_2d()[0].map(f64::to_bits);
+        assert_eq!(bits(v.clamp_length_max(length)), bits(v));
+        assert_eq!(bits(v.clamp_length(length, length)), bits(v));
+
+        let long = v * 10.0;
+        let clamped = long.clamp_length_max(length);
+        assert!((clamped.inner_product(&clamped).sqrt() - length).abs() < 1e-15);
+        assert!(clamped.cross(&v).inner_product(&clamped.cross(&v)) < 1e-30);
+        let short = v.clamp_length(2.0 * length, 3.0 * length);
+        assert!((short.inner_product(&short).sqrt() - 2.0 * length).abs() < 1e-15);
+
+        let unit = long.normalize_or(fallback);
+        assert!((unit.inner_product(&unit) - 1.0).abs() < 1e-15);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sampling_grids() {
+        let xs = Vec::<11, f64>::linspace(-1.0, 1.5);
+        assert_eq!((xs[0], xs[10]), (-1.0, 1.5));
+        for i in 1..11 {
+            assert!((xs[i] - xs[i - 1] - 0.25).abs() < 1e-15);
+        }
+
+        let mut order = std::vec::Vec::new();
+        let m = Mat::<2, 3, usize>::from_fn(|i, j| {
+            order.push((i, j));
+            3 * i + j
+        });
+        assert_eq!(order, [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)]);
+        assert_eq!(m.raw_data(), &[[0, 3], [1, 4], [2, 5]]);
+
+        let ys = Vec::<4, f64>::linspace(0.0, 3.0);
+        let (gx, gy) = Mat::<4, 11, f64>::meshgrid(&xs.T(), &ys);
+        assert_eq!((gx[(2, 7)], gy[(2, 7)]), (xs[7], ys[2]));
+        assert_eq!((gx[(3, 10)], gy[(3, 10)]), (1.5, 3.0));
+    }
+
+    #[test]
+    fn test_dot_with_empty_inner_dimension() {
+        let a = Mat::<2, 0, i32>::new([]);
+        let b = Mat::<0, 3, i32>::new([[], [], []]);
+
+        assert_eq!(a.dot(&b), Mat::init(0));
+    }
+
+    #[test]
+    fn test_euclid_with_negative_numerators() {
+        let a = Mat::<2, 2, i32>::new([[-7, 7], [-16, -17]]);
+
+        assert_eq!(a.div_euclid(4), Mat::new([[-2, 1], [-4, -5]]));
+        assert_eq!(a.rem_euclid(4), Mat::new([[1, 3], [0, 3]]));
+        assert_eq!(a.div_euclid(-4), Mat::new([[2, -1], [4, 5]]));
+        assert_eq!(a.rem_euclid(-4), Mat::new([[1, 3], [0, 3]]));
+        assert_eq!(a % 4, Mat::new([[-3, 3], [0, -1]]));
+        assert_eq!(a.div_euclid(4) * 4 + a.rem_euclid(4), a);
+    }
+
+    #[test]
+    fn test_comparisons_with_nan() {
+        let a = Vec3::new([[f32::NAN, 1.0, f32::NAN]]);
+        let b = Vec3::new([[1.0, f32::NAN, f32::NAN]]);
+
+        for mask in [
+            a.cmp_lt(&b),
+            a.cmp_le(&b),
+            a.cmp_gt(&b),
+            a.cmp_ge(&b),
+            a.cmp_eq(&b),
+        ] {
+            assert!(!mask.any());
+        }
+        assert!(!a.cmp_eq(&a).all());
+
+        let picked = Mat::select(&a.cmp_lt(&b), &a, &b);
+        assert_eq!(picked[0], 1.0);
+        assert!(picked[1].is_nan() && picked[2].is_nan());
+    }
+
+    #[test]
+    fn test_axpy_matches_composed_expression() {
+        let y = Mat::<3, 2, i32>::new([[1, 3, -4], [-2, 0, 5]]);
+        let x = Mat::<3, 2, i32>::new([[7, -1, 2], [0, 4, -3]]);
+
+        let mut z = y;
+        z.axpy(-3, &x);
+
+        assert_eq!(z, y + x * -3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bits_key_dedup() {
+        use std::collections::HashMap;
+
+        let mut ids = HashMap::new();
+        let vertices = [
+            Vec3::new([[1.0f32, 2.0, 3.0]]),
+            Vec3::new([[0.0, 0.0, 0.0]]),
+            Vec3::new([[1.0, 2.0, 3.0]]),
+            Vec3::new([[-0.0, 0.0, 0.0]]),
+        ];
+        for v in vertices {
+            let next = ids.len();
+            ids.entry(v.to_bits_key()).or_insert(next);
+        }
+        // The zeros differ in sign bit only, so they stay distinct keys.
+        assert_eq!(ids.len(), 3);
+
+        ids.clear();
+        for v in vertices {
+            let next = ids.len();
+            ids.entry(v.quantized(1e-4).to_bits_key()).or_insert(next);
+        }
+        assert_eq!(ids.len(), 2);
+
+        let nan = Vec3::new([[f32::NAN, 0.0, 0.0]]);
+        assert_eq!(nan.to_bits_key(), nan.to_bits_key());
+    }
+
+    #[test]
+    fn test_cross_matrix() {
+        let vectors = [
+            Vec3::new([[1, 2, 3]]),
+            Vec3::new([[-4, 0, 7]]),
+            Vec3::new([[0, 0, 0]]),
+            Vec3::new([[5, -6, -1]]),
+        ];
+        for w in vectors {
+            let m = w.cross_matrix();
+            assert_eq!(m + m.T(), Mat::init(0));
+            for v in vectors {
+                assert_eq!(m * v, w.cross(&v));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transform_points_matches_homogeneous_product() {
+        use crate::types::Vec4;
+
+        let points = [
+            Vec3::new([[1.0, 2.0, 3.0]]),
+            Vec3::new([[-0.5, 0.0, 4.0]]),
+            Vec3::new([[0.0, -3.0, 0.25]]),
+        ];
+        let affine = Mat4::<f64>::translation(1.0, -2.0, 0.5) * Mat4::<f64>::scaling(2.0, 0.5, 3.0);
+        let mut projective = affine;
+        projective[(3, 2)] = -1.0;
+        projective[(3, 3)] = 0.0;
+        assert!(affine.is_affine() && !projective.is_affine());
+
+        for m in [affine, projective] {
+            let mut transformed = points;
+            m.transform_points(&mut transformed);
+            let mut dirs = points;
+            m.transform_vectors(&mut dirs);
+
+            for ((p, t), d) in points.iter().zip(transformed).zip(dirs) {
+                let h = m * Vec4::from((*p, 1.0));
+                let expected = Vec3::new([[h[0], h[1], h[2]]]) / h[3];
+                let error = t - expected;
+                assert!(error.raw_data()[0].iter().all(|e| e.abs() < 1e-12));
+                assert_eq!(t, m.transform_point(*p));
+
+                let h = m * Vec4::from((*p, 0.0));
+                assert_eq!(d, Vec3::new([[h[0], h[1], h[2]]]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mat2_rotation_and_inverse() {
+        use crate::Deg;
+
+        let r = Mat2::from_angle(Deg(90.0f64));
+        let y = r * Vec2::new([[1.0, 0.0]]);
+        assert!(y.x().abs() < 1e-15 && (y.y() - 1.0).abs() < 1e-15);
+
+        for angle in [0.3, -1.2, 2.9] {
+            let r = Mat2::<f64>::from_angle(angle);
+            let error = r.inverse().unwrap() - r.T();
+            assert!(error.raw_data().iter().flatten().all(|e| e.abs() < 1e-15));
+            assert!((r.determinant() - 1.0).abs() < 1e-15);
+        }
+
+        let m = Mat2::from_scale_angle(Vec2::new([[2.0, 0.5]]), 0.7);
+        let error = m * m.inverse().unwrap() - Mat2::I();
+        assert!(error.raw_data().iter().flatten().all(|e| e.abs() < 1e-15));
+
+        assert_eq!(Mat2::<f64>::init(1.0).inverse(), None);
+        assert_eq!(Mat2::<f64>::ZERO.inverse(), None);
+    }
+
+    #[test]
+    fn test_least_squares() {
+        let a =
+            Mat3::<f64>::from_rows_array_2d([[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]]);
+        let b = Vec3::new([[3.0, 5.0, 5.0]]);
+        let x = a.solve_least_squares(&b).unwrap();
+        let error = a * x - b;
+        assert!(error.inner_product(&error) < 1e-24);
+
+        // The residual of a least-squares solution is orthogonal to the columns.
+        let a =
+            Mat::<4, 2, f64>::from_rows_array_2d([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 4.0]]);
+        let b = Vec::<4, f64>::new([[0.0, 2.0, 1.0, 5.0]]);
+        let residual = a * a.solve_least_squares(&b).unwrap() - b;
+        let projection = a.T() * residual;
+        assert!(projection.inner_product(&projection) < 1e-24);
+
+        let dependent = Mat::<3, 2, f64>::from_rows_array_2d([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        assert_eq!(dependent.solve_least_squares(&b.xyz()), None);
+        let wide = Mat::<2, 3, f64>::from_rows_array_2d([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        assert_eq!(wide.solve_least_squares(&b.xy()), None);
+        assert_eq!(
+            Mat3::<f64>::ZERO.solve_least_squares(&Vec3::<f64>::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn test_mat4_inverse_and_determinant() {
+        let r = Mat2::<f64>::from_angle(0.7).to_rows_array_2d();
+        let mut m = Mat4::<f64>::from_rows_array_2d([
+            [r[0][0], r[0][1], 0.0, 0.0],
+            [r[1][0], r[1][1], 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        m = Mat4::<f64>::translation(1.0, -2.0, 0.5) * m * Mat4::<f64>::scaling(2.0, 0.5, 3.0);
+        m[(0, 2)] = 0.25;
+
+        let inverse = m.inverse().unwrap();
+        let error = inverse * m - Mat4::I();
+        assert!(error.raw_data().iter().flatten().all(|e| e.abs() < 1e-12));
+        assert!((m.determinant() - 3.0).abs() < 1e-12);
+        assert!((inverse.determinant() * m.determinant() - 1.0).abs() < 1e-12);
+
+        assert_eq!(Mat4::<f64>::ZERO.inverse(), None);
+        assert_eq!(Mat4::<f64>::init(2.0).inverse(), None);
+    }
+
+    #[test]
+    fn test_covariance_along_one_axis() {
+        let dir = Vec3::new([[1.0, 2.0, -2.0]]) / 3.0;
+        let origin = Vec3::new([[0.5, -1.0, 4.0]]);
+        let points = [-2.0, -0.5, 0.0, 1.5, 3.0].map(|t| origin + dir * t);
+        let (mean, cov) = Mat3::covariance(&points);
+
+        let error = mean - (origin + dir * 0.4);
+        assert!(error.raw_data()[0].iter().all(|e| e.abs() < 1e-12));
+        // All the variance lies along `dir`: cov == variance * dir * dirᵀ.
+        let variance = (dir.T() * cov * dir).into_scalar();
+        assert!(variance > 0.0);
+        let error = cov - Mat3::from_outer_product(&dir, &dir) * variance;
+        assert!(error.raw_data().iter().flatten().all(|e| e.abs() < 1e-12));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_inertia_of_symmetric_points_is_diagonal() {
+        let center = Vec3::new([[3.0, -2.0, 1.0]]);
+        let mut points = std::vec::Vec::new();
+        for (offset, mass) in [([1.0, 2.0, 0.5], 2.0), ([0.0, 1.0, 3.0], 0.5)] {
+            for sx in [-1.0, 1.0] {
+                for sy in [-1.0, 1.0] {
+                    for sz in [-1.0, 1.0] {
+                        let r = Vec3::new([[offset[0] * sx, offset[1] * sy, offset[2] * sz]]);
+                        points.push((center + r, mass));
+                    }
+                }
+            }
+        }
+        let inertia = Mat3::inertia_tensor(&points);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                if i == j {
+                    assert!(inertia[(i, j)] > 0.0);
+                } else {
+                    assert!(inertia[(i, j)].abs() < 1e-12);
+                }
+            }
+        }
+        // Ixx = Σ m (y² + z²) = 8 * (2 * (4 + 0.25) + 0.5 * (1 + 9))
+        assert!((inertia[(0, 0)] - 108.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let v = Vec::<4, f64>::new([[2.0, 4.0, 4.0, 6.0]]);
+        assert_eq!(v.sum(), 16.0);
+        assert_eq!(v.mean(), 4.0);
+        assert_eq!(v.variance(), 2.0);
+        assert_eq!(v.min_element(), 2.0);
+        assert_eq!(v.max_element(), 6.0);
+        assert_eq!(v.element_product(), 192.0);
+
+        let flat = Vec3::new([[-1.5, -1.5, -1.5]]);
+        assert_eq!(flat.variance(), 0.0);
+        assert_eq!(flat.std_dev(), 0.0);
+
+        let m = Mat::<2, 3, i32>::new([[3, -7], [0, 2], [9, 1]]);
+        assert_eq!(m.sum(), 8);
+        assert_eq!(m.min_element(), -7);
+        assert_eq!(m.max_element(), 9);
+        assert_eq!(m.element_product(), 0);
+    }
+
+    #[test]
+    fn test_inner_and_matrix_products() {
+        let a = Vec3::new([[1.0, 2.0, 3.0]]);
+        let b = Vec3::new([[-2.0, 0.5, 4.0]]);
+
+        assert_eq!(a.inner_product(&b), 11.0);
+        assert_eq!(a.inner_product(&b), a.T().dot(&b).into_scalar());
+
+        // The matrix product of a vector and a transposed vector is the outer product.
+        let outer: Mat3<f64> = a.dot(&b.T());
+        assert_eq!(outer, Mat::from_outer_product(&a, &b));
+        assert_eq!(outer[(2, 0)], -6.0);
+    }
+
+    #[test]
+    fn test_non_finite_detection() {
+        let mut m = Mat::<3, 4, f64>::default();
+        assert!(m.is_finite() && !m.has_nan());
+        m.assert_finite("zero");
+
+        m[(2, 1)] = f64::NEG_INFINITY;
+        assert!(!m.is_finite() && !m.has_nan());
+        m[(1, 3)] = f64::NAN;
+        assert!(m.has_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "chain: non-finite element at (1, 2)")]
+    fn test_assert_finite_reports_coordinates() {
+        let mut m = Mat3::<f32>::I();
+        m[(1, 2)] = f32::NAN;
+        m.assert_finite("chain");
+    }
+
+    #[test]
+    fn test_grid_post_processing() {
+        let ramp = Mat::<2, 3, f64>::from_rows_array_2d([[10.0, 20.0, 30.0], [40.0, 50.0, 60.0]]);
+
+        let unit = ramp.normalize_to_range(0.0, 1.0).unwrap();
+        assert_eq!(
+            unit,
+            Mat::from_rows_array_2d([[0.0, 0.2, 0.4], [0.6, 0.8, 1.0]])
+        );
+        assert_eq!(ramp.normalize_to_range(-1.0, -1.0), Some(Mat::init(-1.0)));
+        assert_eq!(
+            Mat::<2, 3, f64>::init(7.0).normalize_to_range(0.0, 1.0),
+            None
+        );
+
+        assert_eq!(ramp.percentile(0.0), Some(ramp.min_element()));
+        assert_eq!(ramp.percentile(100.0), Some(ramp.max_element()));
+        assert_eq!(ramp.percentile(40.0), Some(30.0));
+        assert_eq!(Mat::<0, 0, f64>::default().percentile(50.0), None);
+
+        let mut with_nan = ramp;
+        with_nan[(1, 1)] = f64::NAN;
+        assert_eq!(with_nan.percentile(50.0), None);
+
+        assert_eq!(
+            ramp.clamp(25.0, 45.0),
+            Mat::from_rows_array_2d([[25.0, 25.0, 30.0], [40.0, 45.0, 45.0]])
+        );
+    }
+
+    #[test]
+    fn test_tridiagonal_solve() {
+        // Deterministic pseudo-random, diagonally dominant systems.
+        let mut state = 0x2545_f491_u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state % 2001) as f64 / 1000.0 - 1.0
+        };
+
+        for _ in 0..20 {
+            let mut a = Mat::<6, 6, f64>::zero();
+            for i in 0..6 {
+                if i > 0 {
+                    a[(i, i - 1)] = next();
+                }
+                if i < 5 {
+                    a[(i, i + 1)] = next();
+                }
+                a[(i, i)] = 3.0 + next();
+            }
+            let expected = Vec::<6, f64>::new([core::array::from_fn(|_| next())]);
+            let rhs = a * expected;
+
+            let x = a.solve_as_tridiagonal(&rhs, 0.0).unwrap();
+            let error = x - expected;
+            assert!(error.inner_product(&error) < 1e-24);
+        }
+
+        let singular =
+            Mat3::<f64>::from_rows_array_2d([[0.0, 1.0, 0.0], [1.0, 2.0, 1.0], [0.0, 1.0, 2.0]]);
+        assert_eq!(
+            singular.solve_as_tridiagonal(&Vec3::new([[1.0, 1.0, 1.0]]), 0.0),
+            None
+        );
+        assert_eq!(
+            Mat::<1, 1, f64>::solve_tridiagonal(&[9.0], &[4.0], &[9.0], &Vec::new([[2.0]])),
+            Some(Vec::new([[0.5]]))
+        );
+    }
+}
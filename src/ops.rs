@@ -1,8 +1,39 @@
-use crate::{Mat, Zero};
+use crate::{Mat, Vec, Zero};
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::Add<Self> for Mat<ROWS, COLS, T>
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    /// Builds a matrix from `f` applied to each element, shared by the unary and
+    /// scalar operators.
+    #[inline(always)]
+    fn map_ref(&self, mut f: impl FnMut(&T) -> T) -> Self {
+        Self {
+            data: core::array::from_fn(|j| core::array::from_fn(|i| f(&self.data[j][i]))),
+        }
+    }
+
+    /// Panics with the index and the shape of the matrix unless `(row, col)` is in bounds.
+    #[inline(always)]
+    #[track_caller]
+    pub(crate) fn check_index(row: usize, col: usize) {
+        if row >= ROWS || col >= COLS {
+            panic!("index ({row}, {col}) out of bounds for {ROWS}x{COLS} Mat");
+        }
+    }
+
+    /// Builds a matrix from `f` applied to each pair of elements, shared by the
+    /// element-wise binary operators.
+    #[inline(always)]
+    fn zip_ref(&self, rhs: &Self, mut f: impl FnMut(&T, &T) -> T) -> Self {
+        Self {
+            data: core::array::from_fn(|j| {
+                core::array::from_fn(|i| f(&self.data[j][i], &rhs.data[j][i]))
+            }),
+        }
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Add<Self> for Mat<ROWS, COLS, T>
 where
-    T: std::ops::Add<Output = T> + Copy,
+    T: core::ops::Add<Output = T> + Copy,
 {
     type Output = Self;
 
@@ -19,19 +50,14 @@ where
     /// assert_eq!(c, Mat::new([[6.0, 8.0], [10.0, 12.0]]));
     /// ```
     #[inline]
-    fn add(mut self, rhs: Self) -> Self::Output {
-        for i in 0..COLS {
-            for j in 0..ROWS {
-                self.data[i][j] = self.data[i][j] + rhs.data[i][j];
-            }
-        }
-        self
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_ref(&rhs, |&a, &b| a + b)
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::Sub<Self> for Mat<ROWS, COLS, T>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Sub<Self> for Mat<ROWS, COLS, T>
 where
-    T: std::ops::Sub<Output = T> + Copy,
+    T: core::ops::Sub<Output = T> + Copy,
 {
     type Output = Self;
 
@@ -48,19 +74,14 @@ where
     /// assert_eq!(c, Mat::new([[-4.0, -4.0], [-4.0, -4.0]]));
     /// ```
     #[inline]
-    fn sub(mut self, rhs: Self) -> Self::Output {
-        for i in 0..COLS {
-            for j in 0..ROWS {
-                self.data[i][j] = self.data[i][j] - rhs.data[i][j];
-            }
-        }
-        self
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_ref(&rhs, |&a, &b| a - b)
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::Mul<T> for Mat<ROWS, COLS, T>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Mul<T> for Mat<ROWS, COLS, T>
 where
-    T: std::ops::Mul<Output = T> + Copy,
+    T: core::ops::Mul<Output = T> + Copy,
 {
     type Output = Self;
 
@@ -77,19 +98,14 @@ where
     /// assert_eq!(c, Mat::new([[2.0, 4.0], [6.0, 8.0]]));
     /// ```
     #[inline]
-    fn mul(mut self, rhs: T) -> Self::Output {
-        for i in 0..COLS {
-            for j in 0..ROWS {
-                self.data[i][j] = self.data[i][j] * rhs;
-            }
-        }
-        self
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map_ref(|&a| a * rhs)
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::Div<T> for Mat<ROWS, COLS, T>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Div<T> for Mat<ROWS, COLS, T>
 where
-    T: std::ops::Div<Output = T> + Copy,
+    T: core::ops::Div<Output = T> + Copy,
 {
     type Output = Self;
 
@@ -106,19 +122,40 @@ where
     /// assert_eq!(c, Mat::new([[0.5, 1.0], [1.5, 2.0]]));
     /// ```
     #[inline]
-    fn div(mut self, rhs: T) -> Self::Output {
-        for i in 0..COLS {
-            for j in 0..ROWS {
-                self.data[i][j] = self.data[i][j] / rhs;
-            }
-        }
-        self
+    fn div(self, rhs: T) -> Self::Output {
+        self.map_ref(|&a| a / rhs)
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::Neg for Mat<ROWS, COLS, T>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Rem<T> for Mat<ROWS, COLS, T>
 where
-    T: std::ops::Neg<Output = T> + Copy,
+    T: core::ops::Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Matrix remainder by a scalar.
+    ///
+    /// Like `%` on the element type, the result has the sign of the left operand.
+    /// See `rem_euclid` for a remainder that is never negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::new([[7, -7], [8, -8]]);
+    /// let c = a % 3;
+    ///
+    /// assert_eq!(c, Mat::new([[1, -1], [2, -2]]));
+    /// ```
+    #[inline]
+    fn rem(self, rhs: T) -> Self::Output {
+        self.map_ref(|&a| a % rhs)
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Neg for Mat<ROWS, COLS, T>
+where
+    T: core::ops::Neg<Output = T> + Copy,
 {
     type Output = Self;
 
@@ -134,20 +171,15 @@ where
     /// assert_eq!(b, Mat::new([[-1.0, -2.0], [-3.0, -4.0]]));
     /// ```
     #[inline]
-    fn neg(mut self) -> Self::Output {
-        for i in 0..COLS {
-            for j in 0..ROWS {
-                self.data[i][j] = -self.data[i][j];
-            }
-        }
-        self
+    fn neg(self) -> Self::Output {
+        self.map_ref(|&a| -a)
     }
 }
 
 impl<T, const ROWS: usize, const COLS: usize, const OTHER_COLS: usize>
-    std::ops::Mul<Mat<COLS, OTHER_COLS, T>> for Mat<ROWS, COLS, T>
+    core::ops::Mul<Mat<COLS, OTHER_COLS, T>> for Mat<ROWS, COLS, T>
 where
-    T: Copy + Zero + std::ops::AddAssign + std::ops::Mul<Output = T>,
+    T: Copy + Zero + core::ops::Add<Output = T> + core::ops::Mul<Output = T>,
 {
     type Output = Mat<ROWS, OTHER_COLS, T>;
 
@@ -174,7 +206,213 @@ where
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::Index<(usize, usize)>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Add for &Mat<ROWS, COLS, T>
+where
+    T: Clone + core::ops::Add<Output = T>,
+{
+    type Output = Mat<ROWS, COLS, T>;
+
+    /// Matrix addition by reference, which only needs `T: Clone`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Mat::new([[5.0, 6.0], [7.0, 8.0]]);
+    ///
+    /// assert_eq!(&a + &b, a + b);
+    /// ```
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_ref(rhs, |a, b| a.clone() + b.clone())
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Sub for &Mat<ROWS, COLS, T>
+where
+    T: Clone + core::ops::Sub<Output = T>,
+{
+    type Output = Mat<ROWS, COLS, T>;
+
+    /// Matrix subtraction by reference, which only needs `T: Clone`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Mat::new([[5.0, 6.0], [7.0, 8.0]]);
+    ///
+    /// assert_eq!(&a - &b, a - b);
+    /// ```
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_ref(rhs, |a, b| a.clone() - b.clone())
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Mul<T> for &Mat<ROWS, COLS, T>
+where
+    T: Clone + core::ops::Mul<Output = T>,
+{
+    type Output = Mat<ROWS, COLS, T>;
+
+    /// Matrix multiplication of a borrowed matrix by a scalar.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::new([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(&a * 2.0, a * 2.0);
+    /// ```
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map_ref(|a| a.clone() * rhs.clone())
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Div<T> for &Mat<ROWS, COLS, T>
+where
+    T: Clone + core::ops::Div<Output = T>,
+{
+    type Output = Mat<ROWS, COLS, T>;
+
+    /// Matrix division of a borrowed matrix by a scalar.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::new([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(&a / 2.0, a / 2.0);
+    /// ```
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        self.map_ref(|a| a.clone() / rhs.clone())
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Neg for &Mat<ROWS, COLS, T>
+where
+    T: Clone + core::ops::Neg<Output = T>,
+{
+    type Output = Mat<ROWS, COLS, T>;
+
+    /// Negation of a borrowed matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::new([[1.0, 2.0], [3.0, 4.0]]);
+    ///
+    /// assert_eq!(-&a, -a);
+    /// ```
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.map_ref(|a| -a.clone())
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize, const OTHER_COLS: usize>
+    core::ops::Mul<&Mat<COLS, OTHER_COLS, T>> for &Mat<ROWS, COLS, T>
+where
+    T: Clone + core::iter::Sum + core::ops::Mul<Output = T>,
+{
+    type Output = Mat<ROWS, OTHER_COLS, T>;
+
+    /// Matrix product of two borrowed matrices, which only needs `T: Clone` and
+    /// avoids copying large operands.
+    ///
+    /// Each entry is the [`Sum`](core::iter::Sum) of its products, so an empty
+    /// inner dimension gives the sum of nothing rather than needing `Zero`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let a = Mat::<3, 2, i32>::new([[1, 3, -4], [-2, 0, 5]]);
+    /// let b = Mat::<2, 2, i32>::new([[-7, 9], [8, 10]]);
+    ///
+    /// assert_eq!(&a * &b, a * b);
+    /// ```
+    #[inline]
+    fn mul(self, rhs: &Mat<COLS, OTHER_COLS, T>) -> Self::Output {
+        Mat {
+            data: core::array::from_fn(|j| {
+                core::array::from_fn(|i| {
+                    (0..COLS)
+                        .map(|k| self.data[k][i].clone() * rhs.data[j][k].clone())
+                        .sum()
+                })
+            }),
+        }
+    }
+}
+
+// `Vec<1, T> * Vec<1, T>` is already the matrix product of two 1x1 matrices, which
+// gives the same value, so the component-wise product is only defined from size 2 up.
+macro_rules! impl_vec_component_mul {
+    ($($n:literal),*) => {
+        $(
+            impl<T> core::ops::Mul for Vec<$n, T>
+            where
+                T: core::ops::Mul<Output = T> + Copy,
+            {
+                type Output = Self;
+
+                /// Component-wise product of two vectors, as in GLSL.
+                ///
+                /// Use `dot` or transpose one operand for the matrix product.
+                ///
+                /// # Examples
+                /// ```
+                /// use mats::Mat;
+                ///
+                #[doc = concat!("let a = Mat::<", $n, ", 1, i32>::new([[2; ", $n, "]]);")]
+                #[doc = concat!("let b = Mat::<", $n, ", 1, i32>::new([[3; ", $n, "]]);")]
+                ///
+                #[doc = concat!("assert_eq!(a * b, Mat::new([[6; ", $n, "]]));")]
+                /// ```
+                #[inline]
+                fn mul(self, rhs: Self) -> Self::Output {
+                    self.zip_ref(&rhs, |&a, &b| a * b)
+                }
+            }
+        )*
+    };
+}
+
+impl_vec_component_mul!(2, 3, 4);
+
+impl<T, const N: usize> core::ops::Div for Vec<N, T>
+where
+    T: core::ops::Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Component-wise quotient of two vectors, as in GLSL.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Vec4;
+    ///
+    /// let a = Vec4::new([[2.0, 3.0, 4.0, 5.0]]);
+    /// let b = Vec4::new([[4.0, 2.0, 1.0, 0.5]]);
+    ///
+    /// assert_eq!(a / b, Vec4::new([[0.5, 1.5, 4.0, 10.0]]));
+    /// ```
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.zip_ref(&rhs, |&a, &b| a / b)
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::Index<(usize, usize)>
     for Mat<ROWS, COLS, T>
 {
     type Output = T;
@@ -193,12 +431,14 @@ impl<T, const ROWS: usize, const COLS: usize> std::ops::Index<(usize, usize)>
     /// assert_eq!(a[(1, 1)], 4.0);
     /// ```
     #[inline(always)]
+    #[track_caller]
     fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        Self::check_index(row, col);
         &self.data[col][row]
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::IndexMut<(usize, usize)>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::IndexMut<(usize, usize)>
     for Mat<ROWS, COLS, T>
 {
     /// Accessing members in the matrix by indexing.
@@ -214,12 +454,14 @@ impl<T, const ROWS: usize, const COLS: usize> std::ops::IndexMut<(usize, usize)>
     /// assert_eq!(a, Mat::new([[1.0, 5.0], [3.0, 4.0]]));
     /// ```
     #[inline(always)]
+    #[track_caller]
     fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        Self::check_index(row, col);
         &mut self.data[col][row]
     }
 }
 
-impl<T, const SIZE: usize> std::ops::Index<usize> for Mat<SIZE, 1, T> {
+impl<T, const SIZE: usize> core::ops::Index<usize> for Mat<SIZE, 1, T> {
     type Output = T;
 
     /// Accessing members in the vector by indexing.
@@ -235,12 +477,14 @@ impl<T, const SIZE: usize> std::ops::Index<usize> for Mat<SIZE, 1, T> {
     /// assert_eq!(a[2], 3.0);
     /// ```
     #[inline(always)]
+    #[track_caller]
     fn index(&self, index: usize) -> &Self::Output {
+        Self::check_index(index, 0);
         &self.data[0][index]
     }
 }
 
-impl<T, const SIZE: usize> std::ops::IndexMut<usize> for Mat<SIZE, 1, T> {
+impl<T, const SIZE: usize> core::ops::IndexMut<usize> for Mat<SIZE, 1, T> {
     /// Accessing members in the vector by indexing.
     ///
     /// # Examples
@@ -253,14 +497,16 @@ impl<T, const SIZE: usize> std::ops::IndexMut<usize> for Mat<SIZE, 1, T> {
     /// assert_eq!(a, Mat::new([[1.0, 5.0, 3.0]]));
     /// ```
     #[inline(always)]
+    #[track_caller]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        Self::check_index(index, 0);
         &mut self.data[0][index]
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::AddAssign for Mat<ROWS, COLS, T>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::AddAssign for Mat<ROWS, COLS, T>
 where
-    T: Copy + std::ops::AddAssign,
+    T: Copy + core::ops::AddAssign,
 {
     /// Computes the addition of two matrices and assigns the result to left operand.
     ///
@@ -283,9 +529,36 @@ where
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::SubAssign for Mat<ROWS, COLS, T>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::AddAssign<&Self> for Mat<ROWS, COLS, T>
 where
-    T: Copy + std::ops::SubAssign,
+    T: Clone + core::ops::AddAssign,
+{
+    /// Adds a borrowed matrix to the left operand, so `rhs` is not moved.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let parts = [Mat::new([[1.0, 2.0], [3.0, 4.0]]), Mat::new([[5.0, 6.0], [7.0, 8.0]])];
+    /// let mut sum = Mat::<2, 2, f64>::default();
+    /// for part in &parts {
+    ///     sum += part;
+    /// }
+    ///
+    /// assert_eq!(sum, Mat::new([[6.0, 8.0], [10.0, 12.0]]));
+    /// ```
+    fn add_assign(&mut self, rhs: &Self) {
+        for i in 0..COLS {
+            for j in 0..ROWS {
+                self.data[i][j] += rhs.data[i][j].clone();
+            }
+        }
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::SubAssign for Mat<ROWS, COLS, T>
+where
+    T: Copy + core::ops::SubAssign,
 {
     /// Computes the subtraction of two matrices and assigns the result to left operand.
     ///
@@ -308,9 +581,34 @@ where
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::MulAssign<T> for Mat<ROWS, COLS, T>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::SubAssign<&Self> for Mat<ROWS, COLS, T>
+where
+    T: Clone + core::ops::SubAssign,
+{
+    /// Subtracts a borrowed matrix from the left operand, so `rhs` is not moved.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut a = Mat::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Mat::new([[5.0, 6.0], [7.0, 8.0]]);
+    /// a -= &b;
+    ///
+    /// assert_eq!(a, Mat::new([[-4.0, -4.0], [-4.0, -4.0]]));
+    /// ```
+    fn sub_assign(&mut self, rhs: &Self) {
+        for i in 0..COLS {
+            for j in 0..ROWS {
+                self.data[i][j] -= rhs.data[i][j].clone();
+            }
+        }
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::MulAssign<T> for Mat<ROWS, COLS, T>
 where
-    T: Copy + std::ops::MulAssign,
+    T: Copy + core::ops::MulAssign,
 {
     /// Computes the multiplication of a matrix by a scalar and assigns the result to left operand.
     ///
@@ -333,9 +631,9 @@ where
     }
 }
 
-impl<T, const ROWS: usize, const COLS: usize> std::ops::DivAssign<T> for Mat<ROWS, COLS, T>
+impl<T, const ROWS: usize, const COLS: usize> core::ops::DivAssign<T> for Mat<ROWS, COLS, T>
 where
-    T: Copy + std::ops::DivAssign,
+    T: Copy + core::ops::DivAssign,
 {
     /// Computes the division of a matrix by a scalar and assigns the result to left operand.
     ///
@@ -357,3 +655,27 @@ where
         }
     }
 }
+
+impl<T, const ROWS: usize, const COLS: usize> core::ops::RemAssign<T> for Mat<ROWS, COLS, T>
+where
+    T: Copy + core::ops::RemAssign,
+{
+    /// Computes the remainder of a matrix by a scalar and assigns the result to left operand.
+    ///
+    /// # Examples
+    /// ```
+    /// use mats::Mat;
+    ///
+    /// let mut a = Mat::new([[7, -7], [8, -8]]);
+    /// a %= 3;
+    ///
+    /// assert_eq!(a, Mat::new([[1, -1], [2, -2]]));
+    /// ```
+    fn rem_assign(&mut self, rhs: T) {
+        for i in 0..COLS {
+            for j in 0..ROWS {
+                self.data[i][j] %= rhs;
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use rand::{
+    Rng,
+    distr::{Distribution, uniform::SampleUniform},
+};
+
+use crate::{Float, Mat, Vec2, Vec3};
+
+impl<T: Float, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T> {
+    /// Add a sample of `distribution` to every element, in place.
+    ///
+    /// The samples are drawn in column-major order, so a seeded `rng` gives the same
+    /// result on every run.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Mat;
+    /// use rand::{SeedableRng, distr::Uniform, rngs::SmallRng};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let mut m = Mat::<2, 2, f64>::init(10.0);
+    /// m.add_noise(&mut rng, &Uniform::new(-0.5, 0.5).unwrap());
+    ///
+    /// assert!(m.raw_data().iter().flatten().all(|x| (9.5..10.5).contains(x)));
+    /// ```
+    #[inline]
+    pub fn add_noise<R: Rng + ?Sized>(&mut self, rng: &mut R, distribution: &impl Distribution<T>) {
+        for x in self.data.iter_mut().flatten() {
+            *x += distribution.sample(rng);
+        }
+    }
+}
+
+impl<T, const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, T>
+where
+    T: Float + SampleUniform,
+{
+    /// Return a copy with every element moved by a uniform sample from
+    /// `[-amplitude, amplitude]`.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    /// use rand::{SeedableRng, rngs::SmallRng};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let v = Vec3::<f32>::new([[1.0, 2.0, 3.0]]);
+    /// let jittered = v.jitter(&mut rng, 0.01);
+    ///
+    /// assert_ne!(jittered, v);
+    /// assert!((jittered - v).raw_data()[0].iter().all(|e| e.abs() <= 0.01));
+    /// ```
+    #[inline]
+    pub fn jitter<R: Rng + ?Sized>(&self, rng: &mut R, amplitude: T) -> Self {
+        Mat {
+            data: self
+                .data
+                .map(|col| col.map(|x| x + rng.random_range(-amplitude..=amplitude))),
+        }
+    }
+}
+
+impl<T: Float + SampleUniform> Vec2<T> {
+    /// Sample a point uniformly from the open unit disk, by rejection.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    /// use rand::{SeedableRng, rngs::SmallRng};
+    ///
+    /// let p = Vec2::<f64>::random_in_unit_disk(&mut SmallRng::seed_from_u64(1));
+    ///
+    /// assert!(p.inner_product(&p) < 1.0);
+    /// ```
+    #[inline]
+    pub fn random_in_unit_disk<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let one = T::unit_one();
+        loop {
+            let p = Mat {
+                data: [[rng.random_range(-one..one), rng.random_range(-one..one)]],
+            };
+            if p.inner_product(&p) < one {
+                return p;
+            }
+        }
+    }
+}
+
+impl<T: Float + SampleUniform> Vec3<T> {
+    /// Sample a point uniformly from the open unit ball, by rejection.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    /// use rand::{SeedableRng, rngs::SmallRng};
+    ///
+    /// let p = Vec3::<f32>::random_in_unit_sphere(&mut SmallRng::seed_from_u64(1));
+    ///
+    /// assert!(p.inner_product(&p) < 1.0);
+    /// ```
+    #[inline]
+    pub fn random_in_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let one = T::unit_one();
+        loop {
+            let p = Mat {
+                data: [[
+                    rng.random_range(-one..one),
+                    rng.random_range(-one..one),
+                    rng.random_range(-one..one),
+                ]],
+            };
+            if p.inner_product(&p) < one {
+                return p;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, distr::Uniform, rngs::SmallRng};
+
+    use super::*;
+
+    #[test]
+    fn test_noise_bounds() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let base = Mat::<4, 4, f64>::from_fn(|i, j| (4 * j + i) as f64);
+
+        let mut noisy = base;
+        noisy.add_noise(&mut rng, &Uniform::new(-0.25, 0.25).unwrap());
+        let jittered = base.jitter(&mut rng, 0.125);
+        for (x, (y, z)) in base.raw_data().iter().flatten().zip(
+            noisy
+                .raw_data()
+                .iter()
+                .flatten()
+                .zip(jittered.raw_data().iter().flatten()),
+        ) {
+            assert!(x != y && (x - y).abs() < 0.25);
+            assert!(x != z && (x - z).abs() <= 0.125);
+        }
+        assert_eq!(base.jitter(&mut rng, 0.0), base);
+    }
+
+    #[test]
+    fn test_unit_ball_samples() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let n = 10_000;
+
+        let mut disk_sum = Vec2::<f64>::ZERO;
+        let mut sphere_sum = Vec3::<f64>::ZERO;
+        for _ in 0..n {
+            let p = Vec2::random_in_unit_disk(&mut rng);
+            let q = Vec3::random_in_unit_sphere(&mut rng);
+            assert!(p.inner_product(&p) < 1.0 && q.inner_product(&q) < 1.0);
+            disk_sum += p;
+            sphere_sum += q;
+        }
+
+        // The standard error of each mean component is below 0.006.
+        let disk_mean = disk_sum / n as f64;
+        let sphere_mean = sphere_sum / n as f64;
+        assert!(
+            disk_mean.raw_data()[0].iter().all(|e| e.abs() < 0.03),
+            "{disk_mean:?}"
+        );
+        assert!(
+            sphere_mean.raw_data()[0].iter().all(|e| e.abs() < 0.03),
+            "{sphere_mean:?}"
+        );
+    }
+}
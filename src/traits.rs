@@ -1,6 +1,10 @@
 use crate::Mat;
 
 /// A trait requires the type to have a unit one value.
+///
+/// Together with [`Zero`], this is the extension point for custom scalar types:
+/// implement both for a type (e.g. a fixed-point number) to build identity matrices
+/// and multiply matrices of it.
 pub trait UnitOne: Copy {
     /// Returns the unit one value.
     ///
@@ -31,6 +35,8 @@ impl_unit_one!(
 );
 
 /// A trait requires the type to have a zero value.
+///
+/// See [`UnitOne`] for using custom scalar types with `Mat`.
 pub trait Zero: Copy {
     /// Returns the zero value.
     ///
@@ -59,25 +65,28 @@ impl_zero!(
     i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64
 );
 
-impl<T: Zero, const SIZE: usize> Zero for Mat<SIZE, SIZE, T> {
-    /// Returns a zero matrix.
+impl<T: Zero, const ROWS: usize, const COLS: usize> Zero for Mat<ROWS, COLS, T> {
+    /// Returns a zero matrix of any shape.
     ///
     /// # Example
     /// ```
     /// use mats::{Mat, Zero};
     ///
     /// let mat = Mat::<3, 3>::zero();
+    /// let rect = Mat::<2, 3, i32>::zero();
     ///
     /// assert_eq!(mat.raw_data(), &[[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+    /// assert_eq!(rect.raw_data(), &[[0, 0], [0, 0], [0, 0]]);
     /// ```
     #[inline]
     fn zero() -> Self {
         Self {
-            data: [[T::zero(); SIZE]; SIZE],
+            data: [[T::zero(); ROWS]; COLS],
         }
     }
 }
 
+// The identity only exists for square matrices, unlike the zero matrix.
 impl<T, const SIZE: usize> UnitOne for Mat<SIZE, SIZE, T>
 where
     T: UnitOne + Zero,
@@ -102,16 +111,229 @@ where
     }
 }
 
+/// Adapts a type implementing `num_traits::Zero` and `num_traits::One` to [`Zero`]
+/// and [`UnitOne`], so that it can be the element type of a `Mat`.
+///
+/// The arithmetic operators forward to the wrapped value.
+///
+/// # Example
+/// ```
+/// use mats::{Mat2, Num};
+/// use num_complex::Complex;
+///
+/// let i = Num(Complex::new(0.0, 1.0));
+/// let a = Mat2::new([[i, Num(Complex::new(0.0, 0.0))], [Num(Complex::new(0.0, 0.0)), i]]);
+///
+/// assert_eq!(a * a, -Mat2::I());
+/// ```
+#[cfg(feature = "num-traits")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Num<T>(pub T);
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::Zero + Copy> Zero for Num<T> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self(T::zero())
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::One + Copy> UnitOne for Num<T> {
+    #[inline(always)]
+    fn unit_one() -> Self {
+        Self(T::one())
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> From<T> for Num<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: core::ops::Neg<Output = T>> core::ops::Neg for Num<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+macro_rules! impl_num_ops {
+    ($($op:ident $method:ident $op_assign:ident $method_assign:ident),*) => {
+        $(
+            impl<T: core::ops::$op<Output = T>> core::ops::$op for Num<T> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $method(self, rhs: Self) -> Self {
+                    Self(self.0.$method(rhs.0))
+                }
+            }
+
+            impl<T: core::ops::$op_assign> core::ops::$op_assign for Num<T> {
+                #[inline(always)]
+                fn $method_assign(&mut self, rhs: Self) {
+                    self.0.$method_assign(rhs.0);
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "num-traits")]
+impl_num_ops!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign,
+    Rem rem RemAssign rem_assign
+);
+
+/// A trait for integer types supporting Euclidean division.
+pub trait Euclid: Copy {
+    /// Returns the quotient of Euclidean division, see `i32::div_euclid`.
+    fn div_euclid(self, rhs: Self) -> Self;
+    /// Returns the least nonnegative remainder, see `i32::rem_euclid`.
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_euclid {
+    ($($t:ty),*) => {
+        $(
+            impl Euclid for $t {
+                #[inline(always)]
+                fn div_euclid(self, rhs: Self) -> Self {
+                    <$t>::div_euclid(self, rhs)
+                }
+
+                #[inline(always)]
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_euclid!(
+    i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize
+);
+
+/// A trait for types with a complex conjugate.
+///
+/// For real types the conjugate is the value itself, so generic code written against
+/// this trait handles real and complex matrices alike. Implement it for your own
+/// complex type to use `conjugate_transpose` and `is_hermitian`.
+pub trait Conjugate: Copy {
+    /// The type of the modulus, `Self` for real types.
+    type Real: PartialOrd;
+
+    /// Returns the complex conjugate.
+    fn conjugate(self) -> Self;
+
+    /// Returns the modulus (absolute value).
+    ///
+    /// For signed integers `MIN` saturates to `MAX`, since its absolute value does
+    /// not fit in the type.
+    fn modulus(self) -> Self::Real;
+
+    /// Returns the modulus of `self - other`.
+    ///
+    /// Defaults to `(self - other).modulus()`. The integer impls compute it without
+    /// overflowing; for signed integers a distance above `MAX` saturates to `MAX`.
+    #[inline]
+    fn abs_diff(self, other: Self) -> Self::Real
+    where
+        Self: core::ops::Sub<Output = Self>,
+    {
+        (self - other).modulus()
+    }
+}
+
+macro_rules! impl_conjugate {
+    (unsigned: $($u:ty),*; signed: $($i:ty),*; float: $($f:ty),*) => {
+        $(
+            impl Conjugate for $u {
+                type Real = Self;
+
+                #[inline(always)]
+                fn conjugate(self) -> Self {
+                    self
+                }
+
+                #[inline(always)]
+                fn modulus(self) -> Self {
+                    self
+                }
+
+                #[inline(always)]
+                fn abs_diff(self, other: Self) -> Self {
+                    <$u>::abs_diff(self, other)
+                }
+            }
+        )*
+        $(
+            impl Conjugate for $i {
+                type Real = Self;
+
+                #[inline(always)]
+                fn conjugate(self) -> Self {
+                    self
+                }
+
+                #[inline(always)]
+                fn modulus(self) -> Self {
+                    self.saturating_abs()
+                }
+
+                #[inline(always)]
+                fn abs_diff(self, other: Self) -> Self {
+                    Self::try_from(<$i>::abs_diff(self, other)).unwrap_or(Self::MAX)
+                }
+            }
+        )*
+        $(
+            impl Conjugate for $f {
+                type Real = Self;
+
+                #[inline(always)]
+                fn conjugate(self) -> Self {
+                    self
+                }
+
+                #[inline(always)]
+                fn modulus(self) -> Self {
+                    Float::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_conjugate!(
+    unsigned: u8, u16, u32, u64, u128, usize;
+    signed: i8, i16, i32, i64, i128, isize;
+    float: f32, f64
+);
+
 /// A trait for floating-point types.
 pub trait Float:
     Copy
-    + std::ops::Mul<Output = Self>
-    + std::ops::Div<Output = Self>
-    + std::ops::Neg<Output = Self>
-    + std::ops::Sub<Output = Self>
-    + std::ops::Add<Output = Self>
-    + std::ops::DivAssign
-    + std::ops::AddAssign
+    + core::ops::Mul<Output = Self>
+    + core::ops::Div<Output = Self>
+    + core::ops::Neg<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Add<Output = Self>
+    + core::ops::DivAssign
+    + core::ops::AddAssign
     + Zero
     + UnitOne
     + PartialOrd
@@ -125,39 +347,390 @@ pub trait Float:
     fn sqrt(self) -> Self;
     fn abs(self) -> Self;
     fn tan(self) -> Self;
+
+    /// `e` raised to the power of `self`, see `f64::exp`.
+    ///
+    /// Defaults to `eⁿ` by repeated squaring, where `n = floor(self)`, times a
+    /// Taylor series for the fractional part.
+    #[inline]
+    fn exp(self) -> Self {
+        let zero = Self::zero();
+        if !self.is_finite() {
+            return if self < zero { zero } else { self };
+        }
+        let one = Self::unit_one();
+        let two = one + one;
+        let whole = self.floor();
+        let e = exp_unit(one);
+        let mut base = if whole < zero { one / e } else { e };
+        let mut n = whole.abs();
+        let mut power = one;
+        while n >= one {
+            let half = (n / two).floor();
+            if n - half * two >= one {
+                power = power * base;
+            }
+            n = half;
+            base = base * base;
+            if base == zero || !base.is_finite() {
+                if n >= one {
+                    power = power * base;
+                }
+                break;
+            }
+        }
+        power * exp_unit(self - whole)
+    }
+
+    /// The natural logarithm of `self`, see `f64::ln`.
+    ///
+    /// Defaults to splitting `self` into `m · 2ᵏ` with `m` in `[1, 2)` and
+    /// summing the series of `2 atanh((m - 1) / (m + 1))`, which takes a number
+    /// of steps proportional to the exponent of `self`.
+    #[inline]
+    #[allow(clippy::eq_op)]
+    fn ln(self) -> Self {
+        let zero = Self::zero();
+        let one = Self::unit_one();
+        if self.is_nan() || self < zero {
+            return zero / zero;
+        } else if self == zero {
+            return -one / zero;
+        } else if !self.is_finite() {
+            return self;
+        }
+        let two = one + one;
+        let mut m = self;
+        let mut k = zero;
+        while m >= two {
+            m /= two;
+            k += one;
+        }
+        while m < one {
+            m = m * two;
+            k = k - one;
+        }
+        let ln_2 = two * atanh_small(one / (two + one));
+        k * ln_2 + two * atanh_small((m - one) / (m + one))
+    }
+
+    /// `self * a + b` with a single rounding, see `f64::mul_add`.
+    ///
+    /// Defaults to a separate multiply and add, which rounds twice; implement it
+    /// for a custom type that has a fused operation.
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    /// Four-quadrant arctangent of `self / other`, see `f64::atan2`.
+    ///
+    /// Defaults to a Taylor series after argument reduction, built from `sqrt`.
+    /// The default does not distinguish signed zeros and returns NaN when both
+    /// arguments are infinite; implement it for a custom type that has its own.
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        let half_pi = Self::PI / (Self::unit_one() + Self::unit_one());
+        if self.is_nan() || other.is_nan() {
+            self + other
+        } else if self.abs() <= other.abs() {
+            if other == Self::zero() {
+                return Self::zero();
+            }
+            let a = atan_unit(self / other);
+            if other > Self::zero() {
+                a
+            } else if self < Self::zero() {
+                a - Self::PI
+            } else {
+                a + Self::PI
+            }
+        } else if self > Self::zero() {
+            half_pi - atan_unit(other / self)
+        } else {
+            -half_pi - atan_unit(other / self)
+        }
+    }
+
+    /// The largest integer less than or equal to `self`, see `f64::floor`.
+    ///
+    /// Defaults to summing the powers of two that fit below `self.abs()`, which
+    /// takes a number of steps proportional to its exponent.
+    #[inline]
+    fn floor(self) -> Self {
+        if !self.is_finite() {
+            return self;
+        }
+        let one = Self::unit_one();
+        let a = self.abs();
+        let mut power = one;
+        while power + power <= a {
+            power = power + power;
+        }
+        let mut whole = Self::zero();
+        while power >= one {
+            if whole + power <= a {
+                whole += power;
+            }
+            power /= one + one;
+        }
+        if self >= Self::zero() {
+            whole
+        } else if whole == a {
+            -whole
+        } else {
+            -(whole + one)
+        }
+    }
+
+    /// The arccosine of `self` in `[0, π]`, see `f64::acos`.
+    ///
+    /// Defaults to `atan2(sqrt(1 - self²), self)`.
+    #[inline]
+    fn acos(self) -> Self {
+        (Self::unit_one() - self * self).sqrt().atan2(self)
+    }
+
+    /// `self` raised to the power of `n`, see `f64::powf`.
+    ///
+    /// Defaults to `exp(n * ln(self))`, which is only defined for positive `self`.
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        (n * self.ln()).exp()
+    }
+
+    /// The smaller of `self` and `other`, see `f64::min`.
+    ///
+    /// If one of the values is NaN, the other is returned.
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        if self.is_nan() || other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The larger of `self` and `other`, see `f64::max`.
+    ///
+    /// If one of the values is NaN, the other is returned.
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        if self.is_nan() || other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The magnitude of `self` with the sign of `sign`, see `f64::copysign`.
+    ///
+    /// The default takes the sign from `sign < 0`, so it cannot see the sign of
+    /// `-0.0` or of NaN.
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        if sign < Self::zero() {
+            -self.abs()
+        } else {
+            self.abs()
+        }
+    }
+
+    /// Whether `self` is NaN, see `f64::is_nan`.
+    #[inline]
+    #[allow(clippy::eq_op)]
+    fn is_nan(self) -> bool {
+        self != self
+    }
+
+    /// Whether `self` is neither infinite nor NaN, see `f64::is_finite`.
+    ///
+    /// Defaults to `self - self == 0`, which fails for both.
+    #[inline]
+    #[allow(clippy::eq_op)]
+    fn is_finite(self) -> bool {
+        self - self == Self::zero()
+    }
+}
+
+/// The arctangent of `z` for `|z| <= 1`, used by the default `Float::atan2`.
+///
+/// Three applications of `atan(z) = 2 atan(z / (1 + sqrt(1 + z²)))` bring `|z|`
+/// below `tan(π/32)`, where the Taylor series converges quickly.
+fn atan_unit<T: Float>(z: T) -> T {
+    let one = T::unit_one();
+    let two = one + one;
+    let mut z = z;
+    for _ in 0..3 {
+        z = z / (one + (one + z * z).sqrt());
+    }
+    let z2 = z * z;
+    let mut power = z;
+    let mut n = one;
+    let mut sum = z;
+    loop {
+        power = -power * z2;
+        n += two;
+        let term = power / n;
+        sum += term;
+        if term.is_nan() || term.abs() <= T::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    let eight = two * two * two;
+    sum * eight
 }
 
+/// `e` raised to the power of `x` for `x` in `[0, 1]`, used by the default
+/// `Float::exp`.
+///
+/// Finds how many Taylor terms matter, then sums them from the smallest up as
+/// `1 + x/1 (1 + x/2 (1 + …))` to keep the rounding error near one ulp.
+fn exp_unit<T: Float>(x: T) -> T {
+    let one = T::unit_one();
+    let mut n = T::zero();
+    let mut term = one;
+    while term > T::EPSILON {
+        n += one;
+        term = term * x / n;
+    }
+    let mut sum = one;
+    while n >= one {
+        sum = one + x / n * sum;
+        n = n - one;
+    }
+    sum
+}
+
+/// The inverse hyperbolic tangent of `z` for `|z| <= 1/3`, used by the default
+/// `Float::ln`.
+fn atanh_small<T: Float>(z: T) -> T {
+    let one = T::unit_one();
+    let two = one + one;
+    let z2 = z * z;
+    let mut power = z;
+    let mut n = one;
+    let mut sum = z;
+    loop {
+        power = power * z2;
+        n += two;
+        let term = power / n;
+        sum += term;
+        if term.abs() <= T::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    sum
+}
+
+/// Calls the float intrinsic `$std` from `std`, or its `libm` counterpart
+/// `$libm` when the crate is built without `std`.
+macro_rules! float_intrinsic {
+    ($t:ty, $std:ident, $libm:ident, $($x:expr),+) => {{
+        #[cfg(feature = "std")]
+        {
+            <$t>::$std($($x),+)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            libm::Libm::<$t>::$libm($($x),+)
+        }
+    }};
+}
+
+pub(crate) use float_intrinsic;
+
 macro_rules! impl_float {
     ($($t:tt),*) => {
         $(
             impl Float for $t {
-                const PI: Self = std::$t::consts::PI;
+                const PI: Self = core::$t::consts::PI;
                 const STRAIGHT: Self = 180.0 as _;
-                const EPSILON: Self = std::$t::EPSILON;
+                const EPSILON: Self = $t::EPSILON;
 
                 #[inline(always)]
                 fn cos(self) -> Self {
-                    self.cos()
+                    float_intrinsic!($t, cos, cos, self)
                 }
 
                 #[inline(always)]
                 fn sin(self) -> Self {
-                    self.sin()
+                    float_intrinsic!($t, sin, sin, self)
                 }
 
                 #[inline(always)]
                 fn sqrt(self) -> Self {
-                    self.sqrt()
+                    float_intrinsic!($t, sqrt, sqrt, self)
                 }
 
                 #[inline(always)]
                 fn abs(self) -> Self {
-                    self.abs()
+                    float_intrinsic!($t, abs, fabs, self)
                 }
 
                 #[inline(always)]
                 fn tan(self) -> Self {
-                    self.tan()
+                    float_intrinsic!($t, tan, tan, self)
+                }
+
+                #[inline(always)]
+                fn floor(self) -> Self {
+                    float_intrinsic!($t, floor, floor, self)
+                }
+
+                #[inline(always)]
+                fn atan2(self, other: Self) -> Self {
+                    float_intrinsic!($t, atan2, atan2, self, other)
+                }
+
+                #[inline(always)]
+                fn exp(self) -> Self {
+                    float_intrinsic!($t, exp, exp, self)
+                }
+
+                #[inline(always)]
+                fn ln(self) -> Self {
+                    float_intrinsic!($t, ln, log, self)
+                }
+
+                #[inline(always)]
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    float_intrinsic!($t, mul_add, fma, self, a, b)
+                }
+
+                #[inline(always)]
+                fn acos(self) -> Self {
+                    float_intrinsic!($t, acos, acos, self)
+                }
+
+                #[inline(always)]
+                fn powf(self, n: Self) -> Self {
+                    float_intrinsic!($t, powf, pow, self, n)
+                }
+
+                #[inline(always)]
+                fn min(self, other: Self) -> Self {
+                    float_intrinsic!($t, min, fmin, self, other)
+                }
+
+                #[inline(always)]
+                fn max(self, other: Self) -> Self {
+                    float_intrinsic!($t, max, fmax, self, other)
+                }
+
+                #[inline(always)]
+                fn copysign(self, sign: Self) -> Self {
+                    float_intrinsic!($t, copysign, copysign, self, sign)
+                }
+
+                #[inline(always)]
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+
+                #[inline(always)]
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
                 }
             }
         )*
@@ -165,3 +738,136 @@ macro_rules! impl_float {
 }
 
 impl_float!(f32, f64);
+
+#[cfg(feature = "half")]
+macro_rules! impl_half_identities {
+    ($($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                #[inline(always)]
+                fn zero() -> Self {
+                    <$t>::ZERO
+                }
+            }
+
+            impl UnitOne for $t {
+                #[inline(always)]
+                fn unit_one() -> Self {
+                    <$t>::ONE
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "half")]
+impl_half_identities!(half::f16, half::bf16);
+
+/// The half-precision types have no math of their own, so the functions are
+/// evaluated in `f32` and rounded back.
+#[cfg(feature = "half")]
+macro_rules! impl_half_float {
+    ($($t:ty),*) => {
+        $(
+            impl Float for $t {
+                const PI: Self = <$t>::PI;
+                const STRAIGHT: Self = <$t>::from_f32_const(180.0);
+                const EPSILON: Self = <$t>::EPSILON;
+
+                #[inline(always)]
+                fn sin(self) -> Self {
+                    <$t>::from_f32(Float::sin(self.to_f32()))
+                }
+
+                #[inline(always)]
+                fn cos(self) -> Self {
+                    <$t>::from_f32(Float::cos(self.to_f32()))
+                }
+
+                #[inline(always)]
+                fn sqrt(self) -> Self {
+                    <$t>::from_f32(Float::sqrt(self.to_f32()))
+                }
+
+                #[inline(always)]
+                fn abs(self) -> Self {
+                    <$t>::from_bits(self.to_bits() & 0x7fff)
+                }
+
+                #[inline(always)]
+                fn tan(self) -> Self {
+                    <$t>::from_f32(Float::tan(self.to_f32()))
+                }
+
+                #[inline(always)]
+                fn atan2(self, other: Self) -> Self {
+                    <$t>::from_f32(Float::atan2(self.to_f32(), other.to_f32()))
+                }
+
+                #[inline(always)]
+                fn floor(self) -> Self {
+                    <$t>::from_f32(Float::floor(self.to_f32()))
+                }
+
+                #[inline(always)]
+                fn exp(self) -> Self {
+                    <$t>::from_f32(Float::exp(self.to_f32()))
+                }
+
+                #[inline(always)]
+                fn ln(self) -> Self {
+                    <$t>::from_f32(Float::ln(self.to_f32()))
+                }
+
+                #[inline(always)]
+                fn acos(self) -> Self {
+                    <$t>::from_f32(Float::acos(self.to_f32()))
+                }
+
+                #[inline(always)]
+                fn powf(self, n: Self) -> Self {
+                    <$t>::from_f32(Float::powf(self.to_f32(), n.to_f32()))
+                }
+
+                #[inline(always)]
+                fn copysign(self, sign: Self) -> Self {
+                    <$t>::from_bits((self.to_bits() & 0x7fff) | (sign.to_bits() & 0x8000))
+                }
+
+                #[inline(always)]
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+
+                #[inline(always)]
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "half")]
+impl_half_float!(half::f16, half::bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::Float;
+
+    #[test]
+    fn test_float_functions() {
+        assert_eq!(Float::acos(1.0f64), 0.0);
+        assert!((Float::acos(-1.0f32) - core::f32::consts::PI).abs() < 1e-6);
+        assert!((Float::exp(1.0f64) - core::f64::consts::E).abs() < 1e-15);
+        assert_eq!(Float::ln(1.0f32), 0.0);
+        assert_eq!(Float::powf(2.0f64, 10.0), 1024.0);
+        assert_eq!(Float::min(2.0f32, -3.0), -3.0);
+        assert_eq!(Float::max(f64::NAN, 4.0), 4.0);
+        assert_eq!(Float::copysign(3.0f64, -0.0), -3.0);
+        assert!(Float::is_nan(f32::NAN) && !Float::is_nan(1.0f32));
+        assert!(Float::is_finite(1e300f64) && !Float::is_finite(f64::INFINITY));
+        assert_eq!(Float::floor(-0.5f64), -1.0);
+        assert_eq!(Float::atan2(1.0f64, 0.0), core::f64::consts::FRAC_PI_2);
+    }
+}
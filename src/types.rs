@@ -30,6 +30,9 @@ pub type Vec3<T = f32> = Vec<3, T>;
 /// A 4-dimensional vector with elements of type `T`.
 pub type Vec4<T = f32> = Vec<4, T>;
 
+/// A row vector with `N` elements of type `T`, the transpose of [`Vec`].
+pub type RowVec<const N: usize, T = f32> = Mat<1, N, T>;
+
 // Like GLSL syntax
 include!(concat!(env!("OUT_DIR"), "/generated.rs"));
 
@@ -111,6 +114,278 @@ impl From<(i32, i32, i32)> for Vec3<i32> {
     }
 }
 
+impl<T, const N: usize> Vec<N, T> {
+    /// Convert the vector into an array of its components.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Vec2, Vec3, Vec4};
+    ///
+    /// assert_eq!(Vec2::new([[1, 2]]).to_array(), [1, 2]);
+    /// assert_eq!(Vec3::new([[1, 2, 3]]).to_array(), [1, 2, 3]);
+    /// assert_eq!(Vec4::new([[1, 2, 3, 4]]).to_array(), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn to_array(self) -> [T; N] {
+        let [col] = self.data;
+        col
+    }
+}
+
+impl<T, const N: usize> Vec<N, T> {
+    /// Convert the column vector into a row vector with the same components.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{Mat3, Vec3};
+    ///
+    /// let v = Vec3::new([[1, 2, 3]]);
+    /// let m = Mat3::I();
+    ///
+    /// // vᵀ M v
+    /// assert_eq!((v.to_row() * m * v)[(0, 0)], 14);
+    /// ```
+    #[inline]
+    pub fn to_row(self) -> RowVec<N, T> {
+        let [col] = self.data;
+        RowVec::new(col.map(|x| [x]))
+    }
+}
+
+impl<T, const N: usize> RowVec<N, T> {
+    /// Convert the row vector into a column vector with the same components.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::{RowVec, Vec3};
+    ///
+    /// let r = RowVec::from([1, 2, 3]);
+    ///
+    /// assert_eq!(r.to_col(), Vec3::new([[1, 2, 3]]));
+    /// ```
+    #[inline]
+    pub fn to_col(self) -> Vec<N, T> {
+        Vec::new([self.data.map(|[x]| x)])
+    }
+}
+
+// For `N == 1` a row vector is also a column vector, which already has these
+// impls, so they are only generated from size 2 up.
+macro_rules! impl_row_vec {
+    ($($n:literal),*) => {
+        $(
+            /// [T; N] -> RowVec
+            impl<T> From<[T; $n]> for RowVec<$n, T> {
+                #[inline]
+                fn from(value: [T; $n]) -> Self {
+                    Self::new(value.map(|x| [x]))
+                }
+            }
+
+            impl<T> core::ops::Index<usize> for RowVec<$n, T> {
+                type Output = T;
+
+                /// Accessing members in the row vector by indexing.
+                ///
+                /// # Examples
+                /// ```
+                /// use mats::RowVec;
+                ///
+                #[doc = concat!("let r = RowVec::from([7; ", $n, "]);")]
+                ///
+                #[doc = concat!("assert_eq!(r[", $n, " - 1], 7);")]
+                /// ```
+                #[inline(always)]
+                #[track_caller]
+                fn index(&self, index: usize) -> &Self::Output {
+                    Self::check_index(0, index);
+                    &self.data[index][0]
+                }
+            }
+
+            impl<T> core::ops::IndexMut<usize> for RowVec<$n, T> {
+                /// Accessing members in the row vector by indexing.
+                ///
+                /// # Examples
+                /// ```
+                /// use mats::RowVec;
+                ///
+                #[doc = concat!("let mut r = RowVec::from([7; ", $n, "]);")]
+                /// r[1] = 5;
+                ///
+                /// assert_eq!(r[(0, 1)], 5);
+                /// ```
+                #[inline(always)]
+                #[track_caller]
+                fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                    Self::check_index(0, index);
+                    &mut self.data[index][0]
+                }
+            }
+        )*
+    };
+}
+
+impl_row_vec!(2, 3, 4);
+
+impl<T> Mat<1, 1, T> {
+    /// Convert a 1x1 matrix, such as the product of a row and a column vector, into
+    /// its only element.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let a = Vec3::new([[1, 2, 3]]);
+    /// let b = Vec3::new([[4, 5, 6]]);
+    ///
+    /// assert_eq!((a.T().dot(&b)).into_scalar(), 32);
+    /// ```
+    #[inline]
+    pub fn into_scalar(self) -> T {
+        let [[x]] = self.data;
+        x
+    }
+}
+
+impl<T: PartialEq> PartialEq<T> for Mat<1, 1, T> {
+    /// Compare a 1x1 matrix with a scalar.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// let v = Vec2::new([[3, 4]]);
+    ///
+    /// assert_eq!(v.T() * v, 25);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.data[0][0] == *other
+    }
+}
+
+// `impl<T> From<Mat<1, 1, T>> for T` is rejected by the orphan rule, so the
+// conversion is provided for the primitive types only.
+macro_rules! impl_from_scalar_mat {
+    ($($t:ty),*) => {
+        $(
+            /// Mat<1, 1> -> T
+            impl From<Mat<1, 1, $t>> for $t {
+                #[inline]
+                fn from(value: Mat<1, 1, $t>) -> Self {
+                    value.into_scalar()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_scalar_mat!(
+    i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64
+);
+
+impl<T> Vec2<T> {
+    /// Convert the vector into a tuple of its components.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// let (x, y) = Vec2::new([[1, 2]]).to_tuple();
+    ///
+    /// assert_eq!((x, y), (1, 2));
+    /// ```
+    #[inline]
+    pub fn to_tuple(self) -> (T, T) {
+        let [[x, y]] = self.data;
+        (x, y)
+    }
+}
+
+impl<T> Vec3<T> {
+    /// Convert the vector into a tuple of its components.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// assert_eq!(Vec3::new([[1, 2, 3]]).to_tuple(), (1, 2, 3));
+    /// ```
+    #[inline]
+    pub fn to_tuple(self) -> (T, T, T) {
+        let [[x, y, z]] = self.data;
+        (x, y, z)
+    }
+}
+
+impl<T> Vec4<T> {
+    /// Convert the vector into a tuple of its components.
+    ///
+    /// # Example
+    /// ```
+    /// use mats::Vec4;
+    ///
+    /// assert_eq!(Vec4::new([[1, 2, 3, 4]]).to_tuple(), (1, 2, 3, 4));
+    /// ```
+    #[inline]
+    pub fn to_tuple(self) -> (T, T, T, T) {
+        let [[x, y, z, w]] = self.data;
+        (x, y, z, w)
+    }
+}
+
+// `From<Vec3<T>> for (T, T, T)` is rejected by the orphan rule, so these go the
+// other way round.
+
+/// Vec2 -> (T, T)
+#[allow(clippy::from_over_into)]
+impl<T> Into<(T, T)> for Vec2<T> {
+    /// # Example
+    /// ```
+    /// use mats::Vec2;
+    ///
+    /// let t: (i32, i32) = Vec2::new([[1, 2]]).into();
+    /// assert_eq!(t, (1, 2));
+    /// ```
+    #[inline]
+    fn into(self) -> (T, T) {
+        self.to_tuple()
+    }
+}
+
+/// Vec3 -> (T, T, T)
+#[allow(clippy::from_over_into)]
+impl<T> Into<(T, T, T)> for Vec3<T> {
+    /// # Example
+    /// ```
+    /// use mats::Vec3;
+    ///
+    /// let t: (i32, i32, i32) = Vec3::new([[1, 2, 3]]).into();
+    /// assert_eq!(t, (1, 2, 3));
+    /// ```
+    #[inline]
+    fn into(self) -> (T, T, T) {
+        self.to_tuple()
+    }
+}
+
+/// Vec4 -> (T, T, T, T)
+#[allow(clippy::from_over_into)]
+impl<T> Into<(T, T, T, T)> for Vec4<T> {
+    /// # Example
+    /// ```
+    /// use mats::Vec4;
+    ///
+    /// let t: (i32, i32, i32, i32) = Vec4::new([[1, 2, 3, 4]]).into();
+    /// assert_eq!(t, (1, 2, 3, 4));
+    /// ```
+    #[inline]
+    fn into(self) -> (T, T, T, T) {
+        self.to_tuple()
+    }
+}
+
 /// (T, T, T, T) -> Vec4
 impl<T: Copy> From<(T, T, T, T)> for Vec4<T> {
     #[inline]
@@ -119,6 +394,140 @@ impl<T: Copy> From<(T, T, T, T)> for Vec4<T> {
     }
 }
 
+macro_rules! impl_float_to_int {
+    ($f:ty => $($i:ty: $floor:ident, $round:ident, $ceil:ident);*) => {
+        impl<const N: usize> Vec<N, $f> {
+            $(
+                #[doc = concat!("Round each component towards negative infinity and convert it to `", stringify!($i), "`.")]
+                ///
+                /// Out-of-range values saturate at the bounds of the integer type and NaN becomes `0`.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Vec2;
+                ///
+                #[doc = concat!("let v = Vec2::<", stringify!($f), ">::new([[1.5, 2.9]]);")]
+                ///
+                #[doc = concat!("assert_eq!(v.", stringify!($floor), "(), Vec2::new([[1, 2]]));")]
+                /// ```
+                #[inline]
+                pub fn $floor(self) -> Vec<N, $i> {
+                    Vec::new(self.data.map(|col| col.map(|x| crate::traits::float_intrinsic!($f, floor, floor, x) as $i)))
+                }
+
+                #[doc = concat!("Round each component to the nearest integer, halfway cases away from zero, and convert it to `", stringify!($i), "`.")]
+                ///
+                /// Out-of-range values saturate at the bounds of the integer type and NaN becomes `0`.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Vec2;
+                ///
+                #[doc = concat!("let v = Vec2::<", stringify!($f), ">::new([[1.5, 2.4]]);")]
+                ///
+                #[doc = concat!("assert_eq!(v.", stringify!($round), "(), Vec2::new([[2, 2]]));")]
+                /// ```
+                #[inline]
+                pub fn $round(self) -> Vec<N, $i> {
+                    Vec::new(self.data.map(|col| col.map(|x| crate::traits::float_intrinsic!($f, round, round, x) as $i)))
+                }
+
+                #[doc = concat!("Round each component towards positive infinity and convert it to `", stringify!($i), "`.")]
+                ///
+                /// Out-of-range values saturate at the bounds of the integer type and NaN becomes `0`.
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Vec2;
+                ///
+                #[doc = concat!("let v = Vec2::<", stringify!($f), ">::new([[1.5, 2.0]]);")]
+                ///
+                #[doc = concat!("assert_eq!(v.", stringify!($ceil), "(), Vec2::new([[2, 2]]));")]
+                /// ```
+                #[inline]
+                pub fn $ceil(self) -> Vec<N, $i> {
+                    Vec::new(self.data.map(|col| col.map(|x| crate::traits::float_intrinsic!($f, ceil, ceil, x) as $i)))
+                }
+            )*
+        }
+    };
+}
+
+impl_float_to_int!(f32 =>
+    i32: floor_to_i32, round_to_i32, ceil_to_i32;
+    i64: floor_to_i64, round_to_i64, ceil_to_i64;
+    u32: floor_to_u32, round_to_u32, ceil_to_u32
+);
+impl_float_to_int!(f64 =>
+    i32: floor_to_i32, round_to_i32, ceil_to_i32;
+    i64: floor_to_i64, round_to_i64, ceil_to_i64;
+    u32: floor_to_u32, round_to_u32, ceil_to_u32
+);
+
+macro_rules! impl_int_to_float {
+    ($i:ty => $($f:ty: $name:ident),*) => {
+        impl<const N: usize> Vec<N, $i> {
+            $(
+                #[doc = concat!("Convert each component to `", stringify!($f), "`, rounding to the nearest representable value.")]
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Vec2;
+                ///
+                #[doc = concat!("let v = Vec2::<", stringify!($i), ">::new([[3, 7]]);")]
+                ///
+                #[doc = concat!("assert_eq!(v.", stringify!($name), "(), Vec2::new([[3.0, 7.0]]));")]
+                /// ```
+                #[inline]
+                pub fn $name(self) -> Vec<N, $f> {
+                    Vec::new(self.data.map(|col| col.map(|x| x as $f)))
+                }
+            )*
+        }
+    };
+}
+
+impl_int_to_float!(i32 => f32: as_f32, f64: as_f64);
+impl_int_to_float!(i64 => f32: as_f32, f64: as_f64);
+impl_int_to_float!(u32 => f32: as_f32, f64: as_f64);
+
+#[cfg(feature = "half")]
+macro_rules! impl_half_casts {
+    ($($h:ident: $to:ident),*) => {
+        impl<const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, f32> {
+            $(
+                #[doc = concat!("Convert each element to `half::", stringify!($h), "`, rounding to the nearest representable value.")]
+                ///
+                /// # Example
+                /// ```
+                /// use mats::Mat2;
+                ///
+                #[doc = concat!("let m = Mat2::<f32>::I().", stringify!($to), "();")]
+                ///
+                /// assert_eq!(m.to_f32(), Mat2::I());
+                /// ```
+                #[inline]
+                pub fn $to(self) -> Mat<ROWS, COLS, half::$h> {
+                    Mat::new(self.data.map(|col| col.map(half::$h::from_f32)))
+                }
+            )*
+        }
+
+        $(
+            impl<const ROWS: usize, const COLS: usize> Mat<ROWS, COLS, half::$h> {
+                /// Convert each element to `f32`, which is exact.
+                #[inline]
+                pub fn to_f32(self) -> Mat<ROWS, COLS, f32> {
+                    Mat::new(self.data.map(|col| col.map(half::$h::to_f32)))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "half")]
+impl_half_casts!(f16: to_f16, bf16: to_bf16);
+
 /// If the `uniforms` feature is enabled, this module provides implementations of the `AsUniformValue`
 /// trait for the types defined in this module.
 ///
@@ -171,6 +580,69 @@ mod uniforms {
         }
     }
 
+    impl AsUniformValue for Vec2<i32> {
+        #[inline]
+        fn as_uniform_value(&self) -> UniformValue<'_> {
+            UniformValue::IntVec2(self.data[0])
+        }
+    }
+
+    impl AsUniformValue for Vec2<u32> {
+        #[inline]
+        fn as_uniform_value(&self) -> UniformValue<'_> {
+            UniformValue::UnsignedIntVec2(self.data[0])
+        }
+    }
+
+    impl AsUniformValue for Vec2<bool> {
+        #[inline]
+        fn as_uniform_value(&self) -> UniformValue<'_> {
+            UniformValue::BoolVec2(self.data[0])
+        }
+    }
+
+    impl AsUniformValue for Vec3<i32> {
+        #[inline]
+        fn as_uniform_value(&self) -> UniformValue<'_> {
+            UniformValue::IntVec3(self.data[0])
+        }
+    }
+
+    impl AsUniformValue for Vec3<u32> {
+        #[inline]
+        fn as_uniform_value(&self) -> UniformValue<'_> {
+            UniformValue::UnsignedIntVec3(self.data[0])
+        }
+    }
+
+    impl AsUniformValue for Vec3<bool> {
+        #[inline]
+        fn as_uniform_value(&self) -> UniformValue<'_> {
+            UniformValue::BoolVec3(self.data[0])
+        }
+    }
+
+    impl AsUniformValue for Vec4<i32> {
+        #[inline]
+        fn as_uniform_value(&self) -> UniformValue<'_> {
+            UniformValue::IntVec4(self.data[0])
+        }
+    }
+
+    impl AsUniformValue for Vec4<u32> {
+        #[inline]
+        fn as_uniform_value(&self) -> UniformValue<'_> {
+            UniformValue::UnsignedIntVec4(self.data[0])
+        }
+    }
+
+    impl AsUniformValue for Vec4<bool> {
+        #[inline]
+        fn as_uniform_value(&self) -> UniformValue<'_> {
+            UniformValue::BoolVec4(self.data[0])
+        }
+    }
+
     impl AsUniformValue for Mat2<f32> {
         #[inline]
         fn as_uniform_value(&self) -> UniformValue<'_> {
@@ -214,6 +686,71 @@ mod uniforms {
     }
 }
 
+/// If the `encase` feature is enabled, this module implements `encase::ShaderType` for the
+/// vector and square matrix types defined in this module.
+///
+/// This allows these types to be laid out in WGSL uniform and storage buffers, including the
+/// 16-byte alignment of `vec3` and the column padding of `mat3`.
+#[cfg(feature = "encase")]
+mod shader {
+    use encase::{
+        matrix::{AsMutMatrixParts, AsRefMatrixParts, FromMatrixParts, MatrixScalar},
+        vector::{AsMutVectorParts, AsRefVectorParts, FromVectorParts, VectorScalar},
+    };
+
+    use super::*;
+
+    impl<T: VectorScalar, const N: usize> AsRefVectorParts<T, N> for Vec<N, T> {
+        #[inline]
+        fn as_ref_parts(&self) -> &[T; N] {
+            &self.data[0]
+        }
+    }
+
+    impl<T: VectorScalar, const N: usize> AsMutVectorParts<T, N> for Vec<N, T> {
+        #[inline]
+        fn as_mut_parts(&mut self) -> &mut [T; N] {
+            &mut self.data[0]
+        }
+    }
+
+    impl<T: VectorScalar, const N: usize> FromVectorParts<T, N> for Vec<N, T> {
+        #[inline]
+        fn from_parts(parts: [T; N]) -> Self {
+            Self::new([parts])
+        }
+    }
+
+    impl<T: MatrixScalar, const SIZE: usize> AsRefMatrixParts<T, SIZE, SIZE> for Mat<SIZE, SIZE, T> {
+        #[inline]
+        fn as_ref_parts(&self) -> &[[T; SIZE]; SIZE] {
+            &self.data
+        }
+    }
+
+    impl<T: MatrixScalar, const SIZE: usize> AsMutMatrixParts<T, SIZE, SIZE> for Mat<SIZE, SIZE, T> {
+        #[inline]
+        fn as_mut_parts(&mut self) -> &mut [[T; SIZE]; SIZE] {
+            &mut self.data
+        }
+    }
+
+    impl<T: MatrixScalar, const SIZE: usize> FromMatrixParts<T, SIZE, SIZE> for Mat<SIZE, SIZE, T> {
+        #[inline]
+        fn from_parts(parts: [[T; SIZE]; SIZE]) -> Self {
+            Self::new(parts)
+        }
+    }
+
+    ::encase::impl_vector!(2, Vec2<T>);
+    ::encase::impl_vector!(3, Vec3<T>);
+    ::encase::impl_vector!(4, Vec4<T>);
+
+    ::encase::impl_matrix!(2, 2, Mat2<T>);
+    ::encase::impl_matrix!(3, 3, Mat3<T>);
+    ::encase::impl_matrix!(4, 4, Mat4<T>);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,4 +767,204 @@ mod tests {
             Vec4::new([[2.0, 2.0, 6.0, 6.0]])
         );
     }
+
+    #[test]
+    fn test_generated_swizzles() {
+        let v2 = Vec2::from([1, 2]);
+        let v3 = Vec3::from([1, 2, 3]);
+        let v4 = Vec4::from([1, 2, 3, 4]);
+
+        assert_eq!(v2.yx(), Vec2::from([2, 1]));
+        assert_eq!(v2.yxy(), Vec3::from([2, 1, 2]));
+        assert_eq!(v2.xxyy(), Vec4::from([1, 1, 2, 2]));
+        assert_eq!(v3.z(), 3);
+        assert_eq!(v3.zyx(), Vec3::from([3, 2, 1]));
+        assert_eq!(v3.xzzy(), Vec4::from([1, 3, 3, 2]));
+        assert_eq!(v4.wx(), Vec2::from([4, 1]));
+        assert_eq!(v4.wzyx(), Vec4::from([4, 3, 2, 1]));
+        assert_eq!(v4.www(), Vec3::from([4, 4, 4]));
+    }
+
+    #[test]
+    fn test_component_setters() {
+        let v = Vec4::new([[1, 2, 3, 4]]);
+
+        let mut w = v;
+        w.set_y(0);
+        assert_eq!(w, Vec4::new([[1, 0, 3, 4]]));
+        w.set_w(7);
+        assert_eq!(w, Vec4::new([[1, 0, 3, 7]]));
+
+        assert_eq!(v.with_x(0), Vec4::new([[0, 2, 3, 4]]));
+        assert_eq!(v.with_z(0).with_w(0), Vec4::new([[1, 2, 0, 0]]));
+        assert_eq!(v.xyz().with_y(5), Vec3::new([[1, 5, 3]]));
+        assert_eq!(v.xy().with_y(5), Vec2::new([[1, 5]]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_vector_map_keys() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let cells = [(0, 0), (-1, 2), (3, -4), (-1, 1)].map(Vec2::from);
+
+        let hashed: HashMap<Vec2<i32>, usize> = cells.iter().copied().zip(0..).collect();
+        assert_eq!(hashed.len(), 4);
+        assert_eq!(hashed[&Vec2::from((-1, 2))], 1);
+
+        let sorted: BTreeMap<Vec2<i32>, usize> = cells.iter().copied().zip(0..).collect();
+        assert_eq!(
+            sorted.keys().copied().collect::<std::vec::Vec<_>>(),
+            [(-1, 1), (-1, 2), (0, 0), (3, -4)].map(Vec2::from)
+        );
+    }
+
+    #[cfg(feature = "uniforms")]
+    #[test]
+    fn test_integer_and_bool_uniforms() {
+        use glium::uniforms::{AsUniformValue, UniformValue};
+
+        assert!(matches!(
+            Vec2::new([[1, -2]]).as_uniform_value(),
+            UniformValue::IntVec2([1, -2])
+        ));
+        assert!(matches!(
+            Vec3::new([[1u32, 2, 3]]).as_uniform_value(),
+            UniformValue::UnsignedIntVec3([1, 2, 3])
+        ));
+        assert!(matches!(
+            Vec4::new([[true, false, true, false]]).as_uniform_value(),
+            UniformValue::BoolVec4([true, false, true, false])
+        ));
+    }
+
+    #[cfg(all(feature = "encase", feature = "std"))]
+    #[test]
+    fn test_encase_layout() {
+        use encase::{ShaderType, UniformBuffer};
+
+        fn bytes(values: &[f32]) -> std::vec::Vec<u8> {
+            values.iter().flat_map(|v| v.to_le_bytes()).collect()
+        }
+
+        #[derive(ShaderType)]
+        struct Uniforms {
+            position: Vec3<f32>,
+            normal: Mat3<f32>,
+            uv: Vec2<f32>,
+        }
+
+        let uniforms = Uniforms {
+            position: Vec3::new([[1.0, 2.0, 3.0]]),
+            normal: Mat3::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]),
+            uv: Vec2::new([[10.0, 11.0]]),
+        };
+        let mut buffer = UniformBuffer::new(std::vec::Vec::<u8>::new());
+        buffer.write(&uniforms).unwrap();
+
+        #[rustfmt::skip]
+        let expected = bytes(&[
+            1.0, 2.0, 3.0, 0.0,
+            1.0, 2.0, 3.0, 0.0,
+            4.0, 5.0, 6.0, 0.0,
+            7.0, 8.0, 9.0, 0.0,
+            10.0, 11.0, 0.0, 0.0,
+        ]);
+        assert_eq!(buffer.as_ref(), &expected);
+
+        let read: Uniforms = buffer.create().unwrap();
+        assert_eq!(read.position, uniforms.position);
+        assert_eq!(read.normal, uniforms.normal);
+        assert_eq!(read.uv, uniforms.uv);
+
+        let mut mat4 = UniformBuffer::new(std::vec::Vec::<u8>::new());
+        let m = Mat4::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]);
+        mat4.write(&m).unwrap();
+        assert_eq!(mat4.as_ref(), &bytes(&m.raw_data().concat()));
+    }
+
+    #[test]
+    fn test_rounding_conversions() {
+        let world = Vec3::<f32>::new([[-0.5, -2.5, 3.5]]);
+        assert_eq!(world.floor_to_i32(), Vec3::new([[-1, -3, 3]]));
+        assert_eq!(world.round_to_i32(), Vec3::new([[-1, -3, 4]]));
+        assert_eq!(world.ceil_to_i32(), Vec3::new([[0, -2, 4]]));
+
+        // `as` alone would truncate towards zero and put both of these in tile 0.
+        let tiles = Vec2::<f64>::new([[-0.25, 0.25]]).floor_to_i64();
+        assert_eq!(tiles, Vec2::new([[-1, 0]]));
+        assert_eq!(tiles.as_f64(), Vec2::new([[-1.0, 0.0]]));
+
+        let unsigned = Vec4::<f32>::new([[-1.0, 0.5, 4.2, f32::NAN]]).ceil_to_u32();
+        assert_eq!(unsigned, Vec4::new([[0, 1, 5, 0]]));
+        assert_eq!(
+            Vec2::<i32>::new([[-4, 9]]).as_f32(),
+            Vec2::new([[-4.0, 9.0]])
+        );
+    }
+
+    #[test]
+    fn test_component_wise_vector_ops() {
+        let a = Vec3::new([[1.0, -2.0, 3.0]]);
+        let b = Vec3::new([[4.0, 5.0, -0.5]]);
+        assert_eq!(a * b, Vec3::new([[4.0, -10.0, -1.5]]));
+        assert_eq!(
+            Vec2::new([[3, 4]]) * Vec2::new([[-1, 2]]),
+            Vec2::new([[-3, 8]])
+        );
+
+        let c = Vec4::new([[8.0, -9.0, 1.0, 0.0]]);
+        let d = Vec4::new([[2.0, 3.0, -4.0, 5.0]]);
+        assert_eq!(c / d, Vec4::new([[4.0, -3.0, -0.25, 0.0]]));
+
+        // Square matrices still multiply as matrices, and a vector by its
+        // transpose is still the outer product.
+        let m = Mat3::<i32>::from_rows_array_2d([[1, 2, 0], [0, 1, 0], [0, 0, 1]]);
+        let product: Mat3<i32> = m * m;
+        assert_eq!(
+            product,
+            Mat3::from_rows_array_2d([[1, 4, 0], [0, 1, 0], [0, 0, 1]])
+        );
+        let outer: Mat3<f64> = a * b.T();
+        assert_eq!(outer[(1, 0)], -8.0);
+
+        let one = Vec::<1, i32>::new([[6]]);
+        assert_eq!(one * one, Vec::new([[36]]));
+    }
+
+    #[test]
+    fn test_row_vectors() {
+        let v = Vec3::new([[1.0, 2.0, -1.0]]);
+        let w = Vec3::new([[0.5, 0.0, 2.0]]);
+        let m = Mat3::from_rows_array_2d([[1.0, 0.0, 2.0], [0.0, 3.0, 0.0], [4.0, 0.0, 1.0]]);
+
+        let form: Mat<1, 1, f64> = v.to_row() * m * w;
+        assert_eq!(form, Mat::new([[0.5]]));
+        assert_eq!(v.to_row(), v.T());
+        assert_eq!(v.to_row().to_col(), v);
+
+        let mut r = RowVec::<4, i32>::from([1, 2, 3, 4]);
+        r[3] = 9;
+        assert_eq!(r[0] + r[3], 10);
+        assert_eq!(r.to_col(), Vec4::new([[1, 2, 3, 9]]));
+    }
+
+    #[test]
+    fn test_scalar_conversions() {
+        let a = Vec3::new([[1.0, -2.0, 0.5]]);
+        let b = Vec3::new([[2.0, 1.0, 4.0]]);
+
+        let product = a.T() * b;
+        assert_eq!(product, 2.0);
+        assert_eq!(product.into_scalar(), 2.0);
+        let x: f64 = product.into();
+        assert_eq!(x, 2.0);
+        assert_eq!(product, Mat::new([[2.0]]));
+        assert!(a.T() * a != 0.0);
+    }
 }
@@ -27,3 +27,214 @@ pub fn radian<T: Float>(angle: T) -> T {
 pub fn degree<T: Float>(angle: T) -> T {
     angle * T::STRAIGHT / T::PI
 }
+
+/// Wrap an angle in radians into `(-π, π]`.
+///
+/// # Example
+/// ```
+/// use mats::wrap_angle;
+///
+/// let pi = std::f64::consts::PI;
+///
+/// assert!((wrap_angle(3.0 * pi / 2.0) + pi / 2.0).abs() < 1e-15);
+/// assert_eq!(wrap_angle(-pi), pi);
+/// ```
+#[inline]
+pub fn wrap_angle<T: Float>(angle: T) -> T {
+    let two_pi = T::PI + T::PI;
+    angle + two_pi * ((T::PI - angle) / two_pi).floor()
+}
+
+/// Return the signed shortest difference `to - from` between two angles in
+/// radians, in `(-π, π]`.
+///
+/// # Example
+/// ```
+/// use mats::{angle_diff, radian};
+///
+/// let d = angle_diff(radian(179.0f64), radian(-179.0));
+///
+/// assert!((d - radian(2.0)).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn angle_diff<T: Float>(from: T, to: T) -> T {
+    wrap_angle(to - from)
+}
+
+/// Interpolate between two angles in radians the short way around.
+///
+/// The result is wrapped into `(-π, π]`.
+///
+/// # Example
+/// ```
+/// use mats::{lerp_angle, radian};
+///
+/// let a = lerp_angle(radian(170.0f64), radian(-150.0), 0.5);
+///
+/// assert!((a - radian(-170.0)).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn lerp_angle<T: Float>(from: T, to: T, t: T) -> T {
+    wrap_angle(from + angle_diff(from, to) * t)
+}
+
+/// An angle in radians.
+///
+/// The graphics constructors take angles as `impl Angle<T>`, so they accept a
+/// bare float (read as radians), a `Rad` or a [`Deg`].
+///
+/// # Example
+/// ```
+/// use mats::{Deg, Rad};
+///
+/// let a: Rad<f32> = Deg(180.0).into();
+/// assert_eq!(a, Rad(std::f32::consts::PI));
+/// assert_eq!(a * 0.5, Rad(std::f32::consts::PI / 2.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Rad<T>(pub T);
+
+/// An angle in degrees.
+///
+/// # Example
+/// ```
+/// use mats::{Deg, Rad};
+///
+/// let a: Deg<f32> = Rad(std::f32::consts::PI / 4.0).into();
+/// assert_eq!(a, Deg(45.0));
+/// assert_eq!(a + Deg(45.0), Deg(90.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Deg<T>(pub T);
+
+impl<T: Float> From<Deg<T>> for Rad<T> {
+    #[inline]
+    fn from(value: Deg<T>) -> Self {
+        Rad(radian(value.0))
+    }
+}
+
+impl<T: Float> From<Rad<T>> for Deg<T> {
+    #[inline]
+    fn from(value: Rad<T>) -> Self {
+        Deg(degree(value.0))
+    }
+}
+
+/// A trait for values usable as an angle, converting them to radians.
+///
+/// Implemented for [`Rad`], [`Deg`], and every [`Float`] type, which is read as
+/// radians like before the angle types existed.
+pub trait Angle<T>: Copy {
+    /// Returns the angle in radians.
+    fn to_rad(self) -> Rad<T>;
+}
+
+impl<T: Float> Angle<T> for Rad<T> {
+    #[inline(always)]
+    fn to_rad(self) -> Rad<T> {
+        self
+    }
+}
+
+impl<T: Float> Angle<T> for Deg<T> {
+    #[inline(always)]
+    fn to_rad(self) -> Rad<T> {
+        self.into()
+    }
+}
+
+impl<T: Float> Angle<T> for T {
+    #[inline(always)]
+    fn to_rad(self) -> Rad<T> {
+        Rad(self)
+    }
+}
+
+macro_rules! impl_angle_ops {
+    ($($angle:ident),*) => {
+        $(
+            impl<T: Float> core::ops::Add for $angle<T> {
+                type Output = Self;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    $angle(self.0 + rhs.0)
+                }
+            }
+
+            impl<T: Float> core::ops::Sub for $angle<T> {
+                type Output = Self;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    $angle(self.0 - rhs.0)
+                }
+            }
+
+            impl<T: Float> core::ops::Mul<T> for $angle<T> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: T) -> Self {
+                    $angle(self.0 * rhs)
+                }
+            }
+
+            impl<T: Float> core::ops::Div<T> for $angle<T> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: T) -> Self {
+                    $angle(self.0 / rhs)
+                }
+            }
+
+            impl<T: Float> core::ops::Neg for $angle<T> {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    $angle(-self.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_angle_ops!(Rad, Deg);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_angle_round_trip() {
+        for d in [-720.0, -90.0, 0.0, 30.0, 45.0, 123.25, 360.0] {
+            let back: Deg<f64> = Rad::from(Deg(d)).into();
+            assert!((back.0 - d).abs() < 1e-12);
+        }
+        assert_eq!(-Rad(1.0) - Rad(0.5), Rad(-1.5));
+        assert_eq!(Deg(90.0) / 2.0, Deg(45.0));
+    }
+
+    #[test]
+    fn test_angles_across_the_wrap_boundary() {
+        let pi = core::f64::consts::PI;
+
+        assert!((angle_diff(radian(179.0), radian(-179.0)) - radian(2.0)).abs() < 1e-12);
+        assert!((angle_diff(radian(-179.0), radian(179.0)) + radian(2.0)).abs() < 1e-12);
+
+        let mid = lerp_angle(radian(179.0), radian(-179.0), 0.5);
+        assert!((mid.abs() - pi).abs() < 1e-12);
+
+        for k in -3..=3 {
+            let offset = 2.0 * pi * k as f64;
+            for a in [-3.0, -1.0, 0.0, 0.5, 3.0] {
+                let w = wrap_angle(a + offset);
+                assert!(w > -pi && w <= pi);
+                assert!((w - a).abs() < 1e-12);
+            }
+        }
+    }
+}
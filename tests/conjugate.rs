@@ -0,0 +1,102 @@
+use mats::{Conjugate, Mat2};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+fn c(re: f64, im: f64) -> Complex {
+    Complex { re, im }
+}
+
+impl core::ops::Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        c(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Conjugate for Complex {
+    type Real = f64;
+
+    fn conjugate(self) -> Self {
+        c(self.re, -self.im)
+    }
+
+    fn modulus(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+}
+
+#[test]
+fn test_complex_conjugate_transpose() {
+    let a = Mat2::new([[c(1.0, 2.0), c(3.0, -1.0)], [c(0.0, 4.0), c(5.0, 0.5)]]);
+
+    assert_eq!(
+        a.conjugate(),
+        Mat2::new([[c(1.0, -2.0), c(3.0, 1.0)], [c(0.0, -4.0), c(5.0, -0.5)]])
+    );
+    assert_eq!(
+        a.hermitian(),
+        Mat2::new([[c(1.0, -2.0), c(0.0, -4.0)], [c(3.0, 1.0), c(5.0, -0.5)]])
+    );
+    assert_eq!(a.hermitian().hermitian(), a);
+    assert!(!a.is_hermitian(1e-12));
+}
+
+#[test]
+fn test_is_hermitian() {
+    let h = Mat2::new([[c(2.0, 0.0), c(1.0, -1.0)], [c(1.0, 1.0), c(3.0, 0.0)]]);
+    assert!(h.is_hermitian(0.0));
+    assert_eq!(h.hermitian(), h);
+
+    let not_real_diagonal = Mat2::new([[c(2.0, 1.0), c(1.0, -1.0)], [c(1.0, 1.0), c(3.0, 0.0)]]);
+    assert!(!not_real_diagonal.is_hermitian(0.5));
+    assert!(not_real_diagonal.is_hermitian(2.0));
+}
+
+#[test]
+fn test_real_conjugate_transpose() {
+    let a = Mat2::new([[1.0, -2.0], [3.0, 4.0]]);
+
+    assert_eq!(a.conjugate(), a);
+    assert_eq!(a.conjugate_transpose(), a.transpose());
+    assert!(!a.is_hermitian(1e-9));
+    assert!((a + a.T()).is_hermitian(0.0));
+}
+
+#[test]
+fn test_is_hermitian_rejects_nan() {
+    let a = Mat2::new([[1.0, f64::NAN], [f64::NAN, 2.0]]);
+    assert!(!a.is_hermitian(1.0));
+    assert!(!Mat2::new([[f64::NAN, 0.0], [0.0, 1.0]]).is_hermitian(f64::INFINITY));
+    assert!(!Mat2::<f64>::I().is_hermitian(f64::NAN));
+
+    let h = Mat2::new([
+        [c(1.0, 0.0), c(f64::NAN, 0.0)],
+        [c(f64::NAN, 0.0), c(1.0, 0.0)],
+    ]);
+    assert!(!h.is_hermitian(1.0));
+}
+
+#[test]
+fn test_integer_modulus_saturates() {
+    assert_eq!(i32::MIN.modulus(), i32::MAX);
+    assert_eq!((-7i8).modulus(), 7);
+    assert_eq!(i8::MIN.modulus(), i8::MAX);
+    assert_eq!(200u8.modulus(), 200);
+}
+
+#[test]
+fn test_is_hermitian_on_integers_does_not_overflow() {
+    assert!(!Mat2::<u32>::new([[0, 0], [1, 0]]).is_hermitian(0));
+    assert!(Mat2::<u32>::new([[0, 1], [1, 0]]).is_hermitian(0));
+    assert!(Mat2::<u32>::new([[0, u32::MAX], [0, 0]]).is_hermitian(u32::MAX));
+    assert!(!Mat2::<i8>::new([[0, i8::MIN], [i8::MAX, 0]]).is_hermitian(100));
+
+    assert_eq!(Conjugate::abs_diff(3u32, 7), 4);
+    assert_eq!(Conjugate::abs_diff(i8::MAX, i8::MIN), i8::MAX);
+    assert_eq!(Conjugate::abs_diff(-3i32, 4), 7);
+}
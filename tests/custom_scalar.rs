@@ -0,0 +1,294 @@
+use mats::{Float, Mat, Mat2, Mat3, UnitOne, Vec3, Zero};
+
+/// Forwards the operators to the wrapped value of a newtype scalar.
+macro_rules! impl_binary_op {
+    ($t:ty; $($op:ident, $f:ident);*) => {
+        $(
+            impl core::ops::$op for $t {
+                type Output = Self;
+
+                fn $f(self, rhs: Self) -> Self {
+                    Self(core::ops::$op::$f(self.0, rhs.0))
+                }
+            }
+        )*
+    };
+}
+
+/// A signed 16.16 fixed-point number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Fixed(i32);
+
+impl Fixed {
+    const FRAC_BITS: u32 = 16;
+
+    fn from_int(value: i32) -> Self {
+        Self(value << Self::FRAC_BITS)
+    }
+}
+
+impl_binary_op!(Fixed; Add, add; Sub, sub);
+
+impl core::ops::Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(((self.0 as i64 * rhs.0 as i64) >> Self::FRAC_BITS) as i32)
+    }
+}
+
+impl core::ops::Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Zero for Fixed {
+    fn zero() -> Self {
+        Self(0)
+    }
+}
+
+impl UnitOne for Fixed {
+    fn unit_one() -> Self {
+        Self::from_int(1)
+    }
+}
+
+fn fixed<const ROWS: usize, const COLS: usize>(
+    data: [[i32; ROWS]; COLS],
+) -> Mat<ROWS, COLS, Fixed> {
+    Mat::new(data.map(|col| col.map(Fixed::from_int)))
+}
+
+#[test]
+fn test_construct() {
+    assert_eq!(Mat2::<Fixed>::I(), fixed([[1, 0], [0, 1]]));
+    assert_eq!(Mat3::<Fixed>::new_identity(), Mat3::unit_one());
+    assert_eq!(Mat3::<Fixed>::zero(), fixed([[0; 3]; 3]));
+    assert_eq!(Mat2::init(Fixed::from_int(2)), fixed([[2, 2], [2, 2]]));
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = fixed([[1, 2], [3, 4]]);
+    let b = fixed([[-1, 0], [2, 5]]);
+
+    assert_eq!(a + b, fixed([[0, 2], [5, 9]]));
+    assert_eq!(a - b, fixed([[2, 2], [1, -1]]));
+    assert_eq!(-a, fixed([[-1, -2], [-3, -4]]));
+    assert_eq!(a * Fixed::from_int(3), fixed([[3, 6], [9, 12]]));
+    assert_eq!(a * b, fixed([[-1, -2], [17, 24]]));
+    assert_eq!(a * Mat2::I(), a);
+}
+
+#[test]
+fn test_transpose() {
+    let a = fixed::<2, 3>([[1, 2], [3, 4], [5, 6]]);
+
+    assert_eq!(a.T(), fixed([[1, 3, 5], [2, 4, 6]]));
+    assert_eq!(
+        a.T().dot(&a),
+        fixed([[5, 11, 17], [11, 25, 39], [17, 39, 61]])
+    );
+}
+
+/// Needs nothing but the traits, so it compiles for any shape.
+fn product_or_zero<const R: usize, const K: usize, const C: usize>(
+    a: Option<Mat<R, K, Fixed>>,
+    b: &Mat<K, C, Fixed>,
+) -> Mat<R, C, Fixed> {
+    a.map_or_else(Mat::zero, |a| a.dot(b))
+}
+
+#[test]
+fn test_rectangular_zero() {
+    assert_eq!(Mat::<3, 2, i32>::zero(), Mat::new([[0; 3]; 2]));
+    assert_eq!(Mat::<1, 4, Fixed>::zero(), fixed([[0]; 4]));
+
+    let a = fixed([[1, 0, 2], [0, 1, -1]]);
+    let b = fixed([[2, 3]]);
+    assert_eq!(product_or_zero(Some(a), &b), fixed([[2, 3, 1]]));
+    assert_eq!(product_or_zero(None, &b), Mat::<3, 1, Fixed>::zero());
+}
+
+// A downstream float type only has to implement the traits exported at the crate
+// root; the same `Float`, `Zero` and `UnitOne` are used by every module.
+
+/// A float wrapper that counts as a foreign scalar type.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Real(f64);
+
+impl_binary_op!(Real; Add, add; Sub, sub; Mul, mul; Div, div);
+
+impl core::ops::Neg for Real {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl core::ops::AddAssign for Real {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl core::ops::DivAssign for Real {
+    fn div_assign(&mut self, rhs: Self) {
+        self.0 /= rhs.0;
+    }
+}
+
+impl Zero for Real {
+    fn zero() -> Self {
+        Self(0.0)
+    }
+}
+
+impl UnitOne for Real {
+    fn unit_one() -> Self {
+        Self(1.0)
+    }
+}
+
+impl Float for Real {
+    const PI: Self = Self(core::f64::consts::PI);
+    const STRAIGHT: Self = Self(180.0);
+    const EPSILON: Self = Self(f64::EPSILON);
+
+    fn sin(self) -> Self {
+        Self(self.0.sin())
+    }
+
+    fn cos(self) -> Self {
+        Self(self.0.cos())
+    }
+
+    fn sqrt(self) -> Self {
+        Self(self.0.sqrt())
+    }
+
+    fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    fn tan(self) -> Self {
+        Self(self.0.tan())
+    }
+}
+
+fn real<const N: usize>(values: [f64; N]) -> [Real; N] {
+    values.map(Real)
+}
+
+#[test]
+fn test_float_methods_on_mat() {
+    let m = Mat2::from_rows_array_2d([real([2.0, 1.0]), real([4.0, 3.0])]);
+    assert_eq!(m.determinant(), Real(2.0));
+    assert_eq!(m.inverse().unwrap() * m, Mat2::unit_one());
+
+    let v = Vec3::new([real([1.0, 2.0, 3.0])]);
+    assert_eq!(v.mean(), Real(2.0));
+    assert_eq!(v.inner_product(&v), Real(14.0));
+}
+
+#[cfg(feature = "graphics")]
+#[test]
+fn test_float_in_graphics() {
+    use mats::graphics::rotate2d;
+
+    let r = rotate2d(Real(core::f64::consts::FRAC_PI_2));
+    let p = r * Vec3::new([real([1.0, 0.0, 1.0])]);
+    assert!(p.x().0.abs() < 1e-12);
+    assert!((p.y().0 - 1.0).abs() < 1e-12);
+}
+
+#[cfg(feature = "graphics")]
+#[test]
+fn test_generic_angle_argument() {
+    use mats::{
+        Mat4,
+        graphics::{perspective, rotate3d},
+    };
+
+    fn spin<T: Float>(axis: Vec3<T>, angle: T) -> Mat4<T> {
+        rotate3d(axis, angle)
+    }
+
+    fn project<T: Float>(fov: T) -> Mat4<T> {
+        perspective(
+            fov,
+            T::unit_one(),
+            T::unit_one(),
+            T::unit_one() + T::unit_one(),
+        )
+    }
+
+    let half_pi = Real(core::f64::consts::FRAC_PI_2);
+    let p = spin(Vec3::new([real([0.0, 0.0, 1.0])]), half_pi)
+        * mats::Vec4::new([real([1.0, 0.0, 0.0, 1.0])]);
+    assert!(p.x().0.abs() < 1e-12);
+    assert!((p.y().0 - 1.0).abs() < 1e-12);
+
+    assert!((project(half_pi)[(0, 0)].0 - 1.0).abs() < 1e-12);
+    assert_eq!(
+        project(core::f32::consts::FRAC_PI_2),
+        perspective(core::f32::consts::FRAC_PI_2, 1.0, 1.0, 2.0)
+    );
+}
+
+#[test]
+fn test_default_float_methods() {
+    let close = |a: Real, b: f64| (a.0 - b).abs() < 1e-12;
+
+    for x in [-1.0, -0.5, 0.0, 0.3, 1.0] {
+        assert!(close(Real(x).acos(), x.acos()));
+    }
+    for (y, x) in [
+        (0.0, 1.0),
+        (1.0, 1.0),
+        (3.0, -0.5),
+        (0.0, -2.0),
+        (-1e-3, -4.0),
+        (-7.0, 0.0),
+        (-2.0, 1e6),
+    ] {
+        assert!(close(Real(y).atan2(Real(x)), f64::atan2(y, x)));
+    }
+    assert!(Real(f64::NAN).atan2(Real(1.0)).is_nan());
+    assert!(Real(f64::INFINITY).atan2(Real(f64::INFINITY)).is_nan());
+    for x in [0.0, 0.5, -0.5, 3.0, -3.0, 7.25, -1e17, 1e300, f64::INFINITY] {
+        assert_eq!(Real(x).floor(), Real(x.floor()));
+    }
+    let relative = |a: Real, b: f64| (a.0 - b).abs() <= 1e-13 * b.abs();
+    for x in [
+        0.0, 1e-9, 0.5, -0.5, 1.0, 2.75, -3.5, 100.0, -100.0, 700.0, -700.0,
+    ] {
+        assert!(relative(Real(x).exp(), x.exp()));
+    }
+    assert_eq!(Real(1000.0).exp(), Real(f64::INFINITY));
+    assert_eq!(Real(-1000.0).exp(), Real(0.0));
+    assert_eq!(Real(f64::NEG_INFINITY).exp(), Real(0.0));
+    assert!(Real(f64::NAN).exp().is_nan());
+    for x in [1.0, 1.5, 2.0, 0.1, 3.0, 1e-300, 1e300, core::f64::consts::E] {
+        assert!(close(Real(x).ln(), x.ln()));
+    }
+    assert_eq!(Real(0.0).ln(), Real(f64::NEG_INFINITY));
+    assert_eq!(Real(f64::INFINITY).ln(), Real(f64::INFINITY));
+    assert!(Real(-1.0).ln().is_nan() && Real(f64::NAN).ln().is_nan());
+    assert!(close(Real(2.0).powf(Real(0.5)), core::f64::consts::SQRT_2));
+    assert_eq!(Real(1.0).min(Real(-1.0)), Real(-1.0));
+    assert_eq!(Real(f64::NAN).max(Real(2.0)), Real(2.0));
+    assert_eq!(Real(2.0).max(Real(f64::NAN)), Real(2.0));
+    assert_eq!(Real(-2.5).copysign(Real(1.0)), Real(2.5));
+    assert_eq!(Real(2.5).copysign(Real(-1.0)), Real(-2.5));
+    assert!(Real(f64::NAN).is_nan() && !Real(0.0).is_nan());
+    assert!(Real(1.0).is_finite());
+    assert!(!Real(f64::NEG_INFINITY).is_finite() && !Real(f64::NAN).is_finite());
+    // The default is not fused, so 0.1 * 10 rounds to 1 before the add.
+    assert_eq!(Real(0.1).mul_add(Real(10.0), Real(-1.0)), Real(0.0));
+}
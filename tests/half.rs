@@ -0,0 +1,61 @@
+#![cfg(feature = "half")]
+
+use half::{bf16, f16};
+use mats::{Float, Mat2, Mat4, UnitOne, Vec3};
+
+#[test]
+fn test_round_trip_precision() {
+    let m = Mat4::<f32>::from_rows_array(&[
+        1.0, -0.5, 0.333, 1024.0, //
+        3.3, 2.7, -7.25, 0.001, //
+        100.1, -65.0, 0.2, 9.99, //
+        0.0, 1.0, -1.0, 42.42,
+    ]);
+
+    let check = |back: Mat4<f32>, relative: f32| {
+        for (a, b) in back
+            .raw_data()
+            .iter()
+            .flatten()
+            .zip(m.raw_data().iter().flatten())
+        {
+            assert!((a - b).abs() <= b.abs() * relative, "{a} vs {b}");
+        }
+    };
+    // Round to nearest loses at most half a unit in the last place.
+    check(m.to_f16().to_f32(), f16::EPSILON.to_f32() / 2.0);
+    check(m.to_bf16().to_f32(), bf16::EPSILON.to_f32() / 2.0);
+}
+
+#[test]
+fn test_identity_is_exact() {
+    let i = Mat4::<f16>::unit_one();
+    assert_eq!(i.to_f32(), Mat4::I());
+
+    let m = Mat4::<f32>::from_rows_array(&[
+        1.5, 2.0, -3.0, 0.25, //
+        4.0, -0.125, 6.0, 7.0, //
+        8.0, 9.0, 10.0, 11.0, //
+        12.0, 13.0, 14.5, -15.0,
+    ])
+    .to_f16();
+    assert_eq!(m * i, m);
+    assert_eq!(i * m, m);
+    assert_eq!(m.T().T(), m);
+    assert_eq!((m + m - m) * f16::from_f32(2.0), m + m);
+}
+
+#[test]
+fn test_float_functions() {
+    let x = bf16::from_f32(-2.5);
+    assert_eq!(Float::abs(x), bf16::from_f32(2.5));
+    assert_eq!(Float::floor(x), bf16::from_f32(-3.0));
+    assert_eq!(<f16 as Float>::STRAIGHT.to_f32(), 180.0);
+
+    let v = Vec3::<f16>::new([[f16::from_f32(3.0), f16::from_f32(4.0), f16::ZERO]]);
+    assert_eq!(
+        Float::sqrt(v.x() * v.x() + v.y() * v.y()),
+        f16::from_f32(5.0)
+    );
+    assert_eq!(Mat2::<bf16>::I().to_f32(), Mat2::I());
+}
@@ -0,0 +1,160 @@
+use mats::Mat;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Big(String);
+
+impl core::ops::Add for Big {
+    type Output = Big;
+
+    fn add(self, rhs: Big) -> Big {
+        Big(self.0 + &rhs.0)
+    }
+}
+
+impl core::ops::AddAssign for Big {
+    fn add_assign(&mut self, rhs: Big) {
+        self.0 += &rhs.0;
+    }
+}
+
+impl core::ops::Mul for Big {
+    type Output = Big;
+
+    fn mul(self, rhs: Big) -> Big {
+        Big(format!("{}*{}", self.0, rhs.0))
+    }
+}
+
+impl core::iter::Sum for Big {
+    fn sum<I: Iterator<Item = Big>>(iter: I) -> Big {
+        iter.fold(Big(String::new()), |sum, x| sum + x)
+    }
+}
+
+impl core::ops::Neg for Big {
+    type Output = Big;
+
+    fn neg(self) -> Big {
+        Big(self.0.chars().rev().collect())
+    }
+}
+
+#[test]
+fn rows_round_trip_moves_elements() {
+    let rows = [
+        [Big("a".into()), Big("b".into()), Big("c".into())],
+        [Big("d".into()), Big("e".into()), Big("f".into())],
+    ];
+    let m = Mat::<2, 3, Big>::from_rows_array_2d(rows);
+
+    assert_eq!(m[(0, 1)], Big("b".into()));
+    assert_eq!(m[(1, 0)], Big("d".into()));
+    assert_eq!(m.col_ref(2), &[Big("c".into()), Big("f".into())]);
+
+    let back = m.into_rows_array_2d();
+    assert_eq!(back[1][2], Big("f".into()));
+    assert_eq!(back[0][0], Big("a".into()));
+}
+
+#[test]
+fn fill_with_visits_columns_in_order() {
+    let mut m = Mat::<2, 2, Big>::from_cols_array_2d([
+        [Big(String::new()), Big(String::new())],
+        [Big(String::new()), Big(String::new())],
+    ]);
+    let mut calls = 0;
+    m.fill_with(|i, j| {
+        calls += 1;
+        Big(format!("{calls}:{i}{j}"))
+    });
+
+    assert_eq!(
+        m.into_rows_array_2d(),
+        [
+            [Big("1:00".into()), Big("3:01".into())],
+            [Big("2:10".into()), Big("4:11".into())]
+        ]
+    );
+}
+
+#[test]
+fn reference_ops_clone_elements() {
+    let a = Mat::<1, 2, Big>::new([[Big("ab".into())], [Big("c".into())]]);
+    let b = Mat::<1, 2, Big>::new([[Big("x".into())], [Big("yz".into())]]);
+
+    let sum = &a + &b;
+    assert_eq!(sum, Mat::new([[Big("abx".into())], [Big("cyz".into())]]));
+    assert_eq!(-&sum, Mat::new([[Big("xba".into())], [Big("zyc".into())]]));
+    // Both operands are still usable.
+    assert_eq!(a[(0, 1)], Big("c".into()));
+    assert_eq!(b[(0, 0)], Big("x".into()));
+}
+
+#[test]
+fn reference_product_clones_elements() {
+    let row = Mat::<1, 2, Big>::new([[Big("a".into())], [Big("b".into())]]);
+    let col = Mat::<2, 1, Big>::new([[Big("x".into()), Big("y".into())]]);
+
+    assert_eq!(&row * &col, Mat::new([[Big("a*xb*y".into())]]));
+    assert_eq!(
+        (&col * &row).into_rows_array_2d(),
+        [
+            [Big("x*a".into()), Big("x*b".into())],
+            [Big("y*a".into()), Big("y*b".into())]
+        ]
+    );
+
+    let empty_row = Mat::<1, 0, Big>::new([]);
+    let empty_col = Mat::<0, 1, Big>::new([[]]);
+    assert_eq!(&empty_row * &empty_col, Mat::new([[Big(String::new())]]));
+}
+
+#[test]
+// The operands are `Copy`, but the point is to compare both forms.
+#[allow(clippy::op_ref)]
+fn reference_ops_match_owned_ops() {
+    let a =
+        Mat::<3, 3, f64>::from_rows_array_2d([[1.0, -2.0, 3.5], [0.0, 4.0, -1.0], [2.0, 2.0, 0.5]]);
+    let b =
+        Mat::<3, 3, f64>::from_rows_array_2d([[0.5, 1.0, 0.0], [-3.0, 2.0, 1.0], [1.0, 0.0, -2.0]]);
+
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(&a - &b, a - b);
+    assert_eq!(&a * 1.5, a * 1.5);
+    assert_eq!(&a / 4.0, a / 4.0);
+    assert_eq!(-&a, -a);
+    assert_eq!(&a * &b, a * b);
+}
+
+#[test]
+fn assign_ops_accept_both_forms() {
+    let parts = [
+        Mat::<2, 2, i32>::new([[1, 2], [3, 4]]),
+        Mat::new([[-5, 0], [2, 9]]),
+        Mat::new([[7, 7], [-1, 1]]),
+    ];
+
+    let mut by_ref = Mat::default();
+    let mut by_value = Mat::default();
+    for part in &parts {
+        by_ref += part;
+        by_value += *part;
+    }
+    assert_eq!(by_ref, by_value);
+
+    by_ref -= &parts[0];
+    by_value -= parts[0];
+    assert_eq!(by_ref, by_value);
+
+    // Owned operands with inferred element types keep compiling.
+    let mut m = Mat::new([[1.0, 2.0]]);
+    m += Mat::new([[0.5, 0.5]]);
+    m -= Mat::new([[1.0, 1.0]]);
+    assert_eq!(m, Mat::<2, 1, f32>::new([[0.5, 1.5]]));
+
+    let mut text = Mat::<1, 1, Big>::new([[Big("a".into())]]);
+    let suffix = Mat::new([[Big("b".into())]]);
+    text += &suffix;
+    text += &suffix;
+    assert_eq!(text[(0, 0)], Big("abb".into()));
+}
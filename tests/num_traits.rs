@@ -0,0 +1,37 @@
+#![cfg(feature = "num-traits")]
+
+use mats::{Mat2, Mat3, Num, UnitOne, Zero};
+use num_complex::Complex;
+
+fn c(re: f64, im: f64) -> Num<Complex<f64>> {
+    Num(Complex::new(re, im))
+}
+
+#[test]
+fn test_complex_matrices() {
+    let a = Mat2::new([[c(1.0, 1.0), c(0.0, 2.0)], [c(3.0, 0.0), c(1.0, -1.0)]]);
+    let b = Mat2::new([[c(0.0, 1.0), c(1.0, 0.0)], [c(2.0, 0.0), c(0.0, -1.0)]]);
+
+    assert_eq!(
+        a + b,
+        Mat2::new([[c(1.0, 2.0), c(1.0, 2.0)], [c(5.0, 0.0), c(1.0, -2.0)]])
+    );
+    assert_eq!(
+        a * b,
+        Mat2::new([[c(2.0, 1.0), c(-1.0, -1.0)], [c(2.0, -1.0), c(-1.0, 3.0)]])
+    );
+    assert_eq!(
+        a.T(),
+        Mat2::new([[c(1.0, 1.0), c(3.0, 0.0)], [c(0.0, 2.0), c(1.0, -1.0)]])
+    );
+    assert_eq!(a * Mat2::I(), a);
+    assert_eq!(a.dot(&b), a * b);
+}
+
+#[test]
+fn test_primitives_still_work() {
+    assert_eq!(Mat3::<f32>::zero(), Mat3::init(0.0));
+    assert_eq!(Mat3::<i32>::unit_one(), Mat3::I());
+    assert_eq!(<u8 as UnitOne>::unit_one(), 1);
+    assert_eq!(Num(7u8) % Num(4), Num(3));
+}